@@ -0,0 +1,122 @@
+use tge::prelude::*;
+use tge_ext::event::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+use tge_ext::actions::*;
+use tge_ext::tween::Easing;
+use std::time::Duration;
+
+const TITLE: &str = "Actions";
+const STEP: Duration = Duration::from_millis(10);
+
+mod res {
+    pub const TEXTURE_COIN: &str = "assets/coin.png";
+}
+
+struct App {
+    registry: AssetRegistry,
+    scene: SceneGraph,
+    actions: ActionManager<NodeId>,
+    coin_frames: Vec<Frame>,
+    clock: Duration,
+    paused: bool,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let registry = AssetRegistry::builder()
+            .load::<Texture>(engine, res::TEXTURE_COIN)?
+            .build();
+        let coin_frames = SheetGrid::by_cell_count(8, 1)
+            .origin(Anchor::Center)
+            .split_by_texture_ref(&registry, res::TEXTURE_COIN)?;
+        Ok(Self {
+            registry,
+            scene: SceneGraph::new(),
+            actions: ActionManager::new(),
+            coin_frames,
+            clock: Duration::new(0, 0),
+            paused: false,
+        })
+    }
+
+    fn spawn_coin(&mut self, position: Position) {
+        let animation = Animation::by_fps(10.0, self.coin_frames.clone());
+        let mut node = SceneNode::with_content(animation);
+        node.set_position(position);
+        node.set_scale((2.0, 2.0));
+        let coin = self.scene.add_root(node);
+        self.actions.run(coin, Sequence::new()
+            .then(ScaleTo::new(Duration::from_millis(200), (3.0, 3.0)).easing(Easing::BackOut))
+            .then(Spawn::new()
+                .and(MoveTo::new(Duration::from_millis(800), (40.0, 40.0)).easing(Easing::CubicIn))
+                .and(ScaleTo::new(Duration::from_millis(800), (1.0, 1.0))))
+            .then(FadeOut::new(Duration::from_millis(200)))
+            .then(RemoveSelf::new()));
+    }
+
+    fn step(&mut self) {
+        self.scene.update(STEP);
+        self.actions.update(STEP, &mut self.scene);
+        for coin in self.actions.removed() {
+            self.scene.remove(*coin);
+        }
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {} - Coins: {}{}", TITLE, engine.timer().real_time_fps().round(), self.actions.len(), if self.paused { " - Paused" } else { "" });
+        engine.window().set_title(title);
+
+        if engine.mouse().is_button_pressed(MouseButton::Left) {
+            if let Some(position) = engine.mouse().position() {
+                self.spawn_coin(position);
+            }
+        }
+
+        if !self.paused {
+            self.clock += engine.timer().delta_time();
+            while self.clock >= STEP {
+                self.clock -= STEP;
+                self.step();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(Color::WHITE);
+
+        self.scene.draw(engine.graphics(), &self.registry, None)?;
+
+        Ok(())
+    }
+
+    fn event(&mut self, engine: &mut Engine, event: Event) -> GameResult<bool> {
+        self.handle_event(engine, event)
+    }
+}
+
+impl EventHandler for App {
+    fn on_keyboard_input(&mut self, _: &mut Engine, key: KeyCode, action: KeyAction, repeated: bool) -> GameResult<()> {
+        if action == KeyAction::Down {
+            match key {
+                KeyCode::Space if !repeated => self.paused = !self.paused,
+                KeyCode::Right if self.paused => self.step(),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
@@ -75,42 +75,42 @@ impl Game for App {
         self.animation_coin_normal.draw(
             engine.graphics(),
             &self.registry,
-            Transform::default()
+            Transform2D::default()
                 .scale((4.0, 4.0))
                 .translate((128.0, 32.0)),
         )?;
         self.animation_coin_reversed.draw(
             engine.graphics(),
             &self.registry,
-            Transform::default()
+            Transform2D::default()
                 .scale((4.0, 4.0))
                 .translate((192.0, 32.0)),
         )?;
         self.animation_coin_ping_pong.draw(
             engine.graphics(),
             &self.registry,
-            Transform::default()
+            Transform2D::default()
                 .scale((4.0, 4.0))
                 .translate((256.0, 32.0)),
         )?;
         self.animation_role_1.draw(
             engine.graphics(),
             &self.registry,
-            Transform::default()
+            Transform2D::default()
                 .scale((4.0, 4.0))
                 .translate((128.0, 96.0)),
         )?;
         self.animation_role_2.draw(
             engine.graphics(),
             &self.registry,
-            Transform::default()
+            Transform2D::default()
                 .scale((4.0, 4.0))
                 .translate((128.0, 224.0)),
         )?;
         self.animation_role_3.draw(
             engine.graphics(),
             &self.registry,
-            Transform::default()
+            Transform2D::default()
                 .scale((4.0, 4.0))
                 .translate((128.0, 352.0)),
         )?;
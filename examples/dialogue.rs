@@ -0,0 +1,95 @@
+use tge::prelude::*;
+use tge_ext::event::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+use std::rc::Rc;
+use std::cell::Cell;
+
+const TITLE: &str = "Dialogue";
+
+mod res {
+    pub const FONT_ROBOTO: &str = "assets/Roboto/Roboto-Regular.ttf";
+    pub const TEXTURE_FERRIS: &str = "assets/ferris.png";
+}
+
+const DIALOGUE: &str = "Hello there![pause=0.5] I am [color=#f80]Ferris[/color] [img=assets/ferris.png], \
+    the [color=#4cf]crab[/color] of this little town. \
+    Press [color=#ff0]Space[/color] to skip the typing, and press it again to turn the page.[pause=0.3] \
+    Long lines are wrapped to fit the box, and whatever does not fit is moved to the next page. \
+    That is all for now.[pause=0.5] Goodbye!";
+
+struct App {
+    registry: AssetRegistry,
+    typewriter: Typewriter,
+    blips: Rc<Cell<usize>>,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let registry = AssetRegistry::builder()
+            .load::<Font>(engine, res::FONT_ROBOTO)?
+            .load::<Texture>(engine, res::TEXTURE_FERRIS)?
+            .build();
+        let mut label = RichLabel::new(res::FONT_ROBOTO, DIALOGUE)?;
+        label.set_text_size(24.0);
+        label.set_line_spacing(1.2);
+        let mut typewriter = Typewriter::new(&registry, label, (880.0, 120.0))?;
+        let blips = Rc::new(Cell::new(0));
+        let counter = blips.clone();
+        typewriter.set_on_char(move |c| {
+            if c.is_alphanumeric() {
+                counter.set(counter.get() + 1);
+            }
+        });
+        Ok(Self {
+            registry,
+            typewriter,
+            blips,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {} - Page: {}/{} - Blips: {}", TITLE, engine.timer().real_time_fps().round(), self.typewriter.page_index() + 1, self.typewriter.page_count(), self.blips.get());
+        engine.window().set_title(title);
+        self.typewriter.update(engine.timer().delta_time());
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(Color::BLACK);
+        engine.graphics().draw_sprite(
+            TextureRef::None,
+            SpriteDrawParams::default()
+                .region((0.0, 0.0, 920.0, 160.0))
+                .color((0.1, 0.1, 0.3, 0.9)),
+            Transform::default()
+                .translate((52.0, 420.0)),
+        );
+        self.typewriter.draw(engine.graphics(), &self.registry, Transform2D::default().translate((72.0, 440.0)))?;
+        Ok(())
+    }
+
+    fn event(&mut self, engine: &mut Engine, event: Event) -> GameResult<bool> {
+        self.handle_event(engine, event)
+    }
+}
+
+impl EventHandler for App {
+    fn on_keyboard_input(&mut self, _: &mut Engine, key: KeyCode, action: KeyAction, repeated: bool) -> GameResult<()> {
+        if action == KeyAction::Down && !repeated && key == KeyCode::Space && !self.typewriter.advance() {
+            self.typewriter.reset();
+        }
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
@@ -94,17 +94,20 @@ impl Role {
 }
 
 fn draw_role_system(graphics: &mut Graphics, registry: &AssetRegistry, world: &mut World) -> GameResult {
-    for (_, (role, object)) in world.query::<(&Role, &KinematicObject)>().iter() {
-        role.animation.draw(
-            graphics,
-            registry,
-            Transform::default()
+    let mut query = world.query::<(&Role, &KinematicObject)>();
+    let mut queue = DrawQueue::new();
+    for (_, (role, object)) in query.iter() {
+        queue.push_y_sorted(
+            &role.animation,
+            0,
+            object.position,
+            Transform2D::default()
                 .scale((object.scale, object.scale))
                 .rotate(object.angle)
                 .translate(object.position),
-        )?;
+        );
     }
-    Ok(())
+    queue.flush(graphics, registry)
 }
 
 struct App {
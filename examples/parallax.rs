@@ -0,0 +1,111 @@
+use tge::prelude::*;
+use tge_ext::event::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+
+const TITLE: &str = "Parallax";
+
+mod res {
+    pub const TEXTURE_SKY: &str = "assets/sky.png";
+    pub const TEXTURE_TANK_BODY: &str = "assets/soldier-tank/body.png";
+}
+
+struct App {
+    registry: AssetRegistry,
+    design_size: Size,
+    resolution_adapter: CanvasResolutionAdapter,
+    background: ParallaxBackground,
+    camera: Camera2D,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let registry = AssetRegistry::builder()
+            .load::<Texture>(engine, res::TEXTURE_SKY)?
+            .load::<Texture>(engine, res::TEXTURE_TANK_BODY)?
+            .build();
+        let design_size = Size::new(320.0, 256.0);
+        let resolution_adapter = CanvasResolutionAdapter::new(engine.graphics(), ResolutionPolicy::FixedHeight(design_size.height))?;
+
+        let mut background = ParallaxBackground::new();
+        let mut sky = ParallaxLayer::by_sprite(&Sprite::by_texture_ref(&registry, res::TEXTURE_SKY)?);
+        sky.set_scroll_factor((0.2, 0.0));
+        sky.set_repeat(true, false);
+        sky.set_fit(ParallaxFit::Height);
+        sky.set_velocity((-8.0, 0.0));
+        background.push(sky);
+        let mut tanks = ParallaxLayer::by_sprite(&Sprite::by_texture_ref(&registry, res::TEXTURE_TANK_BODY)?);
+        tanks.set_scroll_factor((1.0, 1.0));
+        tanks.set_repeat(true, false);
+        tanks.set_offset((0.0, 160.0));
+        tanks.tiled_sprite_mut().set_color((0.6, 0.6, 0.6, 1.0));
+        background.push(tanks);
+
+        let camera = Camera2D::by_resolution_adapter(&resolution_adapter);
+        Ok(Self {
+            registry,
+            design_size,
+            resolution_adapter,
+            background,
+            camera,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {} - Policy: {}", TITLE, engine.timer().real_time_fps().round(), self.resolution_adapter.policy());
+        engine.window().set_title(title);
+
+        let delta_time = engine.timer().delta_time();
+        let speed = 160.0 * delta_time.as_secs_f32();
+        let mut position = self.camera.position();
+        if engine.keyboard().is_key_down(KeyCode::Left) {
+            position.x -= speed;
+        }
+        if engine.keyboard().is_key_down(KeyCode::Right) {
+            position.x += speed;
+        }
+        self.camera.measure(&self.resolution_adapter);
+        self.camera.set_position(position);
+        self.background.update(delta_time);
+
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(Color::BLACK);
+        self.resolution_adapter.begin(engine.graphics());
+        self.resolution_adapter.clear(engine.graphics(), Color::WHITE);
+        self.background.draw(engine.graphics(), &self.registry, &self.resolution_adapter, self.camera.position())?;
+        self.resolution_adapter.end(engine.graphics());
+        Ok(())
+    }
+
+    fn event(&mut self, engine: &mut Engine, event: Event) -> GameResult<bool> {
+        self.handle_event(engine, event)
+    }
+}
+
+impl EventHandler for App {
+    fn on_keyboard_input(&mut self, _: &mut Engine, key: KeyCode, action: KeyAction, repeated: bool) -> GameResult<()> {
+        if action == KeyAction::Down && !repeated {
+            match key {
+                KeyCode::Num1 => self.resolution_adapter.set_policy(ResolutionPolicy::Normal),
+                KeyCode::Num2 => self.resolution_adapter.set_policy(ResolutionPolicy::FixedWidth(self.design_size.width)),
+                KeyCode::Num3 => self.resolution_adapter.set_policy(ResolutionPolicy::FixedHeight(self.design_size.height)),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
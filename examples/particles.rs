@@ -0,0 +1,82 @@
+use tge::prelude::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+use tge_ext::particles::*;
+
+const TITLE: &str = "Particles";
+
+mod res {
+    pub const TEXTURE_COIN: &str = "assets/coin.png";
+    pub const PARTICLES_COIN_BURST: &str = "assets/particles/coin_burst.json";
+}
+
+struct App {
+    registry: AssetRegistry,
+    config: ParticleConfig,
+    sprite: ParticleSprite,
+    emitters: Vec<ParticleEmitter>,
+    seed: u64,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let registry = AssetRegistry::builder()
+            .load::<Texture>(engine, res::TEXTURE_COIN)?
+            .build();
+        let config = ParticleConfig::load(res::PARTICLES_COIN_BURST)?;
+        let frames = SheetGrid::by_cell_count(8, 1)
+            .origin(Anchor::Center)
+            .split_by_texture_ref(&registry, res::TEXTURE_COIN)?;
+        let sprite = Animation::by_fps(10.0, frames).into();
+        Ok(Self {
+            registry,
+            config,
+            sprite,
+            emitters: Vec::new(),
+            seed: 0,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {} - Particles: {}", TITLE, engine.timer().real_time_fps().round(), self.emitters.iter().map(|emitter| emitter.len()).sum::<usize>());
+        engine.window().set_title(title);
+
+        if engine.mouse().is_button_pressed(MouseButton::Left) {
+            if let Some(position) = engine.mouse().position() {
+                self.seed += 1;
+                let mut emitter = ParticleEmitter::new(self.config.clone(), self.seed);
+                emitter.set_position(position);
+                self.emitters.push(emitter);
+            }
+        }
+
+        let delta_time = engine.timer().delta_time();
+        for emitter in &mut self.emitters {
+            emitter.update(delta_time);
+        }
+        self.emitters.retain(|emitter| !emitter.is_finished());
+
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(Color::BLACK);
+
+        for emitter in &self.emitters {
+            self.sprite.draw(engine.graphics(), &self.registry, emitter, None)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
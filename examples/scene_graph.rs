@@ -0,0 +1,86 @@
+use tge::prelude::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+
+const TITLE: &str = "Scene Graph";
+
+mod res {
+    pub const TEXTURE_TANK_BODY: &str = "assets/soldier-tank/body.png";
+    pub const TEXTURE_TANK_TURRENT: &str = "assets/soldier-tank/turrent.png";
+}
+
+struct App {
+    registry: AssetRegistry,
+    scene: SceneGraph,
+    tank: NodeId,
+    turrent: NodeId,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let registry = AssetRegistry::builder()
+            .load::<Texture>(engine, res::TEXTURE_TANK_BODY)?
+            .load::<Texture>(engine, res::TEXTURE_TANK_TURRENT)?
+            .build();
+        let mut scene = SceneGraph::new();
+        let tank = scene.add_root({
+            let mut body = Sprite::by_texture_ref(&registry, res::TEXTURE_TANK_BODY)?;
+            body.set_anchor(Anchor::Center);
+            let mut node = SceneNode::with_content(body);
+            node.set_position((512.0, 300.0));
+            node.set_scale((0.5, 0.5));
+            node
+        });
+        let turrent = scene.add_child(tank, {
+            let mut turrent = Sprite::by_texture_ref(&registry, res::TEXTURE_TANK_TURRENT)?;
+            turrent.set_origin((76.5, 350.0));
+            let mut node = SceneNode::with_content(turrent);
+            node.set_position((0.0, 30.0));
+            node
+        })?;
+        Ok(Self {
+            registry,
+            scene,
+            tank,
+            turrent,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {}", TITLE, engine.timer().real_time_fps().round());
+        engine.window().set_title(title);
+
+        let delta_time_f32 = engine.timer().delta_time().as_secs_f32();
+        if let Some(tank) = self.scene.node_mut(self.tank) {
+            tank.set_rotation(tank.rotation() + Angle::radians(0.5 * delta_time_f32));
+        }
+        if let Some(position) = engine.mouse().position() {
+            let target = self.scene.world_to_local(self.tank, position);
+            if let (Some(target), Some(turrent)) = (target, self.scene.node_mut(self.turrent)) {
+                let angle = (target.x - turrent.position().x).atan2(turrent.position().y - target.y);
+                turrent.set_rotation(Angle::radians(angle));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(Color::WHITE);
+
+        self.scene.draw(engine.graphics(), &self.registry, None)?;
+
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
@@ -0,0 +1,98 @@
+use tge::prelude::*;
+use tge_ext::graphics::*;
+
+const TITLE: &str = "Shapes";
+
+struct App {
+    health: f32,
+    rotation: f32,
+}
+
+impl App {
+    fn new(_: &mut Engine) -> GameResult<Self> {
+        Ok(Self {
+            health: 1.0,
+            rotation: 0.0,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {}", TITLE, engine.timer().real_time_fps().round());
+        engine.window().set_title(title);
+
+        let delta_time = engine.timer().delta_time().as_secs_f32();
+        self.health = (self.health - delta_time * 0.2).rem_euclid(1.0);
+        self.rotation += delta_time;
+
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        let graphics = engine.graphics();
+        graphics.clear(Color::BLACK);
+
+        Shape::rect((40.0, 40.0, 300.0, 24.0))
+            .color((0.3, 0.0, 0.0, 1.0))
+            .draw(graphics, None);
+        Shape::rect((40.0, 40.0, 300.0 * self.health, 24.0))
+            .color(Color::RED)
+            .draw(graphics, None);
+        Shape::rect((40.0, 40.0, 300.0, 24.0))
+            .stroke(2.0)
+            .draw(graphics, None);
+
+        Shape::rounded_rect((40.0, 100.0, 300.0, 120.0), 16.0)
+            .color((0.1, 0.1, 0.3, 1.0))
+            .draw(graphics, None);
+        Shape::rounded_rect((40.0, 100.0, 300.0, 120.0), 16.0)
+            .stroke(3.0)
+            .color((0.5, 0.5, 1.0, 1.0))
+            .draw(graphics, None);
+
+        Shape::line((40.0, 260.0), (340.0, 320.0), 6.0)
+            .color((0.0, 1.0, 0.0, 1.0))
+            .draw(graphics, None);
+
+        Shape::circle((520.0, 150.0), 80.0)
+            .color((1.0, 0.8, 0.0, 1.0))
+            .draw(graphics, None);
+        Shape::circle((520.0, 150.0), 90.0)
+            .stroke(4.0)
+            .draw(graphics, None);
+        Shape::arc((520.0, 150.0), 100.0, Angle::radians(self.rotation), Angle::radians(self.rotation + 2.0))
+            .stroke(6.0)
+            .color((0.0, 0.5, 1.0, 1.0))
+            .draw(graphics, None);
+        Shape::arc((520.0, 420.0), 80.0, Angle::degrees(30.0), Angle::degrees(330.0))
+            .fill()
+            .color((1.0, 1.0, 0.0, 1.0))
+            .draw(graphics, None);
+
+        let star = (0..10)
+            .map(|index| {
+                let radius = if index % 2 == 0 { 100.0 } else { 40.0 };
+                let angle = std::f32::consts::PI * index as f32 / 5.0;
+                Position::new(radius * angle.sin(), -radius * angle.cos())
+            })
+            .collect();
+        let transform = Transform2D::default()
+            .rotate(Angle::radians(self.rotation))
+            .translate((820.0, 300.0));
+        Shape::polygon(star)
+            .color((1.0, 0.5, 0.0, 1.0))
+            .draw(graphics, transform);
+
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
@@ -0,0 +1,80 @@
+use tge::prelude::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+use tge_ext::tilemap::*;
+
+const TITLE: &str = "Tilemap";
+
+mod res {
+    pub const TILEMAP_COINS: &str = "assets/tilemap/coins.tmx";
+}
+
+struct App {
+    registry: AssetRegistry,
+    tilemap: Tilemap,
+    camera: Camera2D,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let mut registry = AssetRegistry::new();
+        let tilemap = Tilemap::load(res::TILEMAP_COINS)?;
+        tilemap.register_textures(engine, &mut registry)?;
+        let map_size = tilemap.size();
+        let mut camera = Camera2D::new((1024.0, 600.0));
+        camera.set_zoom(2.0);
+        camera.set_bounds(Some(Region::new(0.0, 0.0, map_size.width, map_size.height)));
+        if let Some(spawn) = tilemap.object_layer("objects").and_then(|layer| layer.object_by_name("spawn")) {
+            camera.set_position(spawn.position);
+        }
+        Ok(Self {
+            registry,
+            tilemap,
+            camera,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {}", TITLE, engine.timer().real_time_fps().round());
+        engine.window().set_title(title);
+
+        let delta_time = engine.timer().delta_time();
+        let distance = 300.0 * delta_time.as_secs_f32();
+        let mut position = self.camera.position();
+        if engine.keyboard().is_key_down(KeyCode::Left) {
+            position.x -= distance;
+        }
+        if engine.keyboard().is_key_down(KeyCode::Right) {
+            position.x += distance;
+        }
+        if engine.keyboard().is_key_down(KeyCode::Up) {
+            position.y -= distance;
+        }
+        if engine.keyboard().is_key_down(KeyCode::Down) {
+            position.y += distance;
+        }
+        self.camera.set_position(position);
+        self.tilemap.update(delta_time);
+
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(self.tilemap.background_color().unwrap_or(Color::WHITE));
+
+        self.tilemap.draw(engine.graphics(), &self.registry, self.camera.visible_region(), self.camera.transform())?;
+
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
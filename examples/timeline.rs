@@ -0,0 +1,84 @@
+use tge::prelude::*;
+use tge_ext::event::*;
+use tge_ext::asset::*;
+use tge_ext::graphics::*;
+use tge_ext::keyframe::*;
+
+const TITLE: &str = "Timeline";
+
+mod res {
+    pub const TEXTURE_FERRIS: &str = "assets/ferris.png";
+    pub const TIMELINE_FERRIS_INTRO: &str = "assets/timeline/ferris_intro.json";
+}
+
+struct App {
+    registry: AssetRegistry,
+    sprite: Sprite,
+    timeline: Timeline,
+}
+
+impl App {
+    fn new(engine: &mut Engine) -> GameResult<Self> {
+        let registry = AssetRegistry::builder()
+            .load::<Texture>(engine, res::TEXTURE_FERRIS)?
+            .build();
+        let sprite = Sprite::by_texture_ref(&registry, res::TEXTURE_FERRIS)?;
+        let timeline = Timeline::load(res::TIMELINE_FERRIS_INTRO)?;
+        Ok(Self {
+            registry,
+            sprite,
+            timeline,
+        })
+    }
+}
+
+impl Game for App {
+    fn update(&mut self, engine: &mut Engine) -> GameResult {
+        let title = format!("{} - FPS: {} - Time: {:.2}s - Play count: {}", TITLE, engine.timer().real_time_fps().round(), self.timeline.time().as_secs_f32(), self.timeline.play_count());
+        engine.window().set_title(title);
+        self.timeline.update(engine.timer().delta_time());
+        Ok(())
+    }
+
+    fn render(&mut self, engine: &mut Engine) -> GameResult {
+        engine.graphics().clear(Color::BLACK);
+        if self.timeline.is_visible() {
+            let sample = self.timeline.sample();
+            self.sprite.set_color(sample.color);
+            self.sprite.draw(engine.graphics(), &self.registry, sample.transform())?;
+        }
+        Ok(())
+    }
+
+    fn event(&mut self, engine: &mut Engine, event: Event) -> GameResult<bool> {
+        self.handle_event(engine, event)
+    }
+}
+
+impl EventHandler for App {
+    fn on_keyboard_input(&mut self, _: &mut Engine, key: KeyCode, action: KeyAction, repeated: bool) -> GameResult<()> {
+        if action == KeyAction::Down && !repeated {
+            match key {
+                KeyCode::Space => if self.timeline.is_paused() {
+                    self.timeline.resume();
+                } else {
+                    self.timeline.pause();
+                },
+                KeyCode::Num1 => self.timeline.set_play_mode(PlayMode::Normal),
+                KeyCode::Num2 => self.timeline.set_play_mode(PlayMode::Reversed),
+                KeyCode::Num3 => self.timeline.set_play_mode(PlayMode::PingPong),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> GameResult {
+    EngineBuilder::new()
+        .window_config(WindowConfig::new()
+            .title(TITLE)
+            .inner_size((1024.0, 600.0)))
+        .build()?
+        .run_with(App::new)
+}
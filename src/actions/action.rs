@@ -0,0 +1,10 @@
+use super::ActionTarget;
+use std::time::Duration;
+
+pub trait Action {
+    fn start(&mut self, target: &mut dyn ActionTarget);
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration;
+
+    fn is_finished(&self) -> bool;
+}
@@ -0,0 +1,187 @@
+use super::{Action, ActionTarget};
+use crate::graphics::RepeatCount;
+use std::time::Duration;
+
+pub struct Sequence {
+    actions: Vec<Box<dyn Action>>,
+    current_index: usize,
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+            current_index: 0,
+        }
+    }
+
+    pub fn then(mut self, action: impl Action + 'static) -> Self {
+        self.actions.push(Box::new(action));
+        self
+    }
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Action for Sequence {
+    fn start(&mut self, target: &mut dyn ActionTarget) {
+        self.current_index = 0;
+        if let Some(action) = self.actions.first_mut() {
+            action.start(target);
+        }
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        let mut remaining = delta_time;
+        while let Some(action) = self.actions.get_mut(self.current_index) {
+            remaining = action.update(target, remaining);
+            if !action.is_finished() {
+                return Duration::new(0, 0);
+            }
+            self.current_index += 1;
+            if let Some(action) = self.actions.get_mut(self.current_index) {
+                action.start(target);
+            }
+        }
+        remaining
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_index >= self.actions.len()
+    }
+}
+
+pub struct Spawn {
+    actions: Vec<Box<dyn Action>>,
+}
+
+impl Spawn {
+    pub fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn and(mut self, action: impl Action + 'static) -> Self {
+        self.actions.push(Box::new(action));
+        self
+    }
+}
+
+impl Default for Spawn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Action for Spawn {
+    fn start(&mut self, target: &mut dyn ActionTarget) {
+        for action in &mut self.actions {
+            action.start(target);
+        }
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        let mut remaining = delta_time;
+        for action in &mut self.actions {
+            if !action.is_finished() {
+                remaining = remaining.min(action.update(target, delta_time));
+            }
+        }
+        if self.is_finished() {
+            remaining
+        } else {
+            Duration::new(0, 0)
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.actions.iter().all(|action| action.is_finished())
+    }
+}
+
+pub struct Repeat {
+    action: Box<dyn Action>,
+    repeat_count: RepeatCount,
+    play_count: usize,
+}
+
+impl Repeat {
+    pub fn new(action: impl Action + 'static, repeat_count: RepeatCount) -> Self {
+        Self {
+            action: Box::new(action),
+            repeat_count,
+            play_count: 0,
+        }
+    }
+
+    pub fn play_count(&self) -> usize {
+        self.play_count
+    }
+}
+
+impl Action for Repeat {
+    fn start(&mut self, target: &mut dyn ActionTarget) {
+        self.play_count = 0;
+        if !self.is_finished() {
+            self.action.start(target);
+        }
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        let mut remaining = delta_time;
+        while !self.is_finished() {
+            let before = remaining;
+            remaining = self.action.update(target, remaining);
+            if !self.action.is_finished() {
+                return Duration::new(0, 0);
+            }
+            self.play_count += 1;
+            if self.is_finished() {
+                break;
+            }
+            self.action.start(target);
+            if self.repeat_count == RepeatCount::Infinite && remaining == before {
+                return Duration::new(0, 0);
+            }
+        }
+        remaining
+    }
+
+    fn is_finished(&self) -> bool {
+        match self.repeat_count {
+            RepeatCount::Infinite => false,
+            RepeatCount::Count(count) => self.play_count >= count,
+        }
+    }
+}
+
+pub struct RepeatForever(Repeat);
+
+impl RepeatForever {
+    pub fn new(action: impl Action + 'static) -> Self {
+        Self(Repeat::new(action, RepeatCount::Infinite))
+    }
+
+    pub fn play_count(&self) -> usize {
+        self.0.play_count()
+    }
+}
+
+impl Action for RepeatForever {
+    fn start(&mut self, target: &mut dyn ActionTarget) {
+        self.0.start(target);
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        self.0.update(target, delta_time)
+    }
+
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,141 @@
+use super::{Action, ActionTarget};
+use tge::prelude::*;
+use std::time::Duration;
+
+type ActionFunc = Box<dyn FnMut(&mut dyn ActionTarget)>;
+
+pub struct CallFunc {
+    func: ActionFunc,
+    finished: bool,
+}
+
+impl CallFunc {
+    pub fn new(func: impl FnMut(&mut dyn ActionTarget) + 'static) -> Self {
+        Self {
+            func: Box::new(func),
+            finished: false,
+        }
+    }
+}
+
+impl Action for CallFunc {
+    fn start(&mut self, _: &mut dyn ActionTarget) {
+        self.finished = false;
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        if !self.finished {
+            (self.func)(target);
+            self.finished = true;
+        }
+        delta_time
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub struct Place {
+    position: Position,
+    finished: bool,
+}
+
+impl Place {
+    pub fn new(position: impl Into<Position>) -> Self {
+        Self {
+            position: position.into(),
+            finished: false,
+        }
+    }
+}
+
+impl Action for Place {
+    fn start(&mut self, _: &mut dyn ActionTarget) {
+        self.finished = false;
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        target.set_position(self.position);
+        self.finished = true;
+        delta_time
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub struct RemoveSelf {
+    finished: bool,
+}
+
+impl RemoveSelf {
+    pub fn new() -> Self {
+        Self {
+            finished: false,
+        }
+    }
+}
+
+impl Default for RemoveSelf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Action for RemoveSelf {
+    fn start(&mut self, _: &mut dyn ActionTarget) {
+        self.finished = false;
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        if !self.finished {
+            target.remove_self();
+            self.finished = true;
+        }
+        delta_time
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub struct SetVisible {
+    visible: bool,
+    finished: bool,
+}
+
+impl SetVisible {
+    pub fn new(visible: bool) -> Self {
+        Self {
+            visible,
+            finished: false,
+        }
+    }
+
+    pub fn show() -> Self {
+        Self::new(true)
+    }
+
+    pub fn hide() -> Self {
+        Self::new(false)
+    }
+}
+
+impl Action for SetVisible {
+    fn start(&mut self, _: &mut dyn ActionTarget) {
+        self.finished = false;
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        target.set_visible(self.visible);
+        self.finished = true;
+        delta_time
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
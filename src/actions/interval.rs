@@ -0,0 +1,361 @@
+use super::{Action, ActionTarget};
+use crate::tween::{Easing, Lerp};
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone)]
+struct Interval {
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl Interval {
+    fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            elapsed: Duration::new(0, 0),
+            easing: Easing::default(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = Duration::new(0, 0);
+    }
+
+    fn advance(&mut self, delta_time: Duration) -> (f32, Duration) {
+        self.elapsed += delta_time;
+        let remaining = self.elapsed.saturating_sub(self.duration);
+        self.elapsed = self.elapsed.min(self.duration);
+        let progress = if self.duration.as_nanos() == 0 {
+            1.0
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        };
+        (self.easing.apply(progress), remaining)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+macro_rules! impl_interval_action {
+    ($name:ident) => {
+        impl $name {
+            pub fn easing(mut self, easing: Easing) -> Self {
+                self.interval.easing = easing;
+                self
+            }
+        }
+
+        impl Action for $name {
+            fn start(&mut self, target: &mut dyn ActionTarget) {
+                self.interval.reset();
+                self.capture(target);
+            }
+
+            fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+                let (progress, remaining) = self.interval.advance(delta_time);
+                self.apply(target, progress);
+                remaining
+            }
+
+            fn is_finished(&self) -> bool {
+                self.interval.is_finished()
+            }
+        }
+    };
+}
+
+pub struct MoveTo {
+    interval: Interval,
+    start_position: Position,
+    position: Position,
+}
+
+impl MoveTo {
+    pub fn new(duration: Duration, position: impl Into<Position>) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_position: Position::zero(),
+            position: position.into(),
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_position = target.position();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        target.set_position(self.start_position.lerp(self.position, progress));
+    }
+}
+
+impl_interval_action!(MoveTo);
+
+pub struct MoveBy {
+    interval: Interval,
+    start_position: Position,
+    offset: Vector,
+}
+
+impl MoveBy {
+    pub fn new(duration: Duration, offset: impl Into<Vector>) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_position: Position::zero(),
+            offset: offset.into(),
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_position = target.position();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        target.set_position(Position::new(
+            self.start_position.x + self.offset.x * progress,
+            self.start_position.y + self.offset.y * progress,
+        ));
+    }
+}
+
+impl_interval_action!(MoveBy);
+
+pub struct RotateTo {
+    interval: Interval,
+    start_rotation: Angle,
+    rotation: Angle,
+}
+
+impl RotateTo {
+    pub fn new(duration: Duration, rotation: Angle) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_rotation: Angle::zero(),
+            rotation,
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_rotation = target.rotation();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        target.set_rotation(self.start_rotation.lerp(self.rotation, progress));
+    }
+}
+
+impl_interval_action!(RotateTo);
+
+pub struct RotateBy {
+    interval: Interval,
+    start_rotation: Angle,
+    rotation: Angle,
+}
+
+impl RotateBy {
+    pub fn new(duration: Duration, rotation: Angle) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_rotation: Angle::zero(),
+            rotation,
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_rotation = target.rotation();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        target.set_rotation(Angle::radians(self.start_rotation.radians_value() + self.rotation.radians_value() * progress));
+    }
+}
+
+impl_interval_action!(RotateBy);
+
+pub struct ScaleTo {
+    interval: Interval,
+    start_scale: Vector,
+    scale: Vector,
+}
+
+impl ScaleTo {
+    pub fn new(duration: Duration, scale: impl Into<Vector>) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_scale: Vector::new(1.0, 1.0),
+            scale: scale.into(),
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_scale = target.scale();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        target.set_scale(self.start_scale.lerp(self.scale, progress));
+    }
+}
+
+impl_interval_action!(ScaleTo);
+
+pub struct ScaleBy {
+    interval: Interval,
+    start_scale: Vector,
+    factor: Vector,
+}
+
+impl ScaleBy {
+    pub fn new(duration: Duration, factor: impl Into<Vector>) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_scale: Vector::new(1.0, 1.0),
+            factor: factor.into(),
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_scale = target.scale();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        let scale = Vector::new(self.start_scale.x * self.factor.x, self.start_scale.y * self.factor.y);
+        target.set_scale(self.start_scale.lerp(scale, progress));
+    }
+}
+
+impl_interval_action!(ScaleBy);
+
+pub struct TintTo {
+    interval: Interval,
+    start_color: Color,
+    color: Color,
+}
+
+impl TintTo {
+    pub fn new(duration: Duration, color: impl Into<Color>) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_color: Color::WHITE,
+            color: color.into(),
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_color = target.color();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        target.set_color(self.start_color.lerp(self.color, progress));
+    }
+}
+
+impl_interval_action!(TintTo);
+
+pub struct FadeTo {
+    interval: Interval,
+    start_color: Color,
+    alpha: f32,
+}
+
+impl FadeTo {
+    pub fn new(duration: Duration, alpha: f32) -> Self {
+        Self {
+            interval: Interval::new(duration),
+            start_color: Color::WHITE,
+            alpha,
+        }
+    }
+
+    fn capture(&mut self, target: &mut dyn ActionTarget) {
+        self.start_color = target.color();
+    }
+
+    fn apply(&self, target: &mut dyn ActionTarget, progress: f32) {
+        let mut color = target.color();
+        color.alpha = self.start_color.alpha.lerp(self.alpha, progress);
+        target.set_color(color);
+    }
+}
+
+impl_interval_action!(FadeTo);
+
+pub struct FadeIn(FadeTo);
+
+impl FadeIn {
+    pub fn new(duration: Duration) -> Self {
+        Self(FadeTo::new(duration, 1.0))
+    }
+
+    pub fn easing(self, easing: Easing) -> Self {
+        Self(self.0.easing(easing))
+    }
+}
+
+impl Action for FadeIn {
+    fn start(&mut self, target: &mut dyn ActionTarget) {
+        self.0.start(target);
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        self.0.update(target, delta_time)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+pub struct FadeOut(FadeTo);
+
+impl FadeOut {
+    pub fn new(duration: Duration) -> Self {
+        Self(FadeTo::new(duration, 0.0))
+    }
+
+    pub fn easing(self, easing: Easing) -> Self {
+        Self(self.0.easing(easing))
+    }
+}
+
+impl Action for FadeOut {
+    fn start(&mut self, target: &mut dyn ActionTarget) {
+        self.0.start(target);
+    }
+
+    fn update(&mut self, target: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        self.0.update(target, delta_time)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+pub struct Wait {
+    interval: Interval,
+}
+
+impl Wait {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            interval: Interval::new(duration),
+        }
+    }
+}
+
+impl Action for Wait {
+    fn start(&mut self, _: &mut dyn ActionTarget) {
+        self.interval.reset();
+    }
+
+    fn update(&mut self, _: &mut dyn ActionTarget, delta_time: Duration) -> Duration {
+        self.interval.advance(delta_time).1
+    }
+
+    fn is_finished(&self) -> bool {
+        self.interval.is_finished()
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Action, ActionTarget, ActionTargetProvider};
+use tge::prelude::*;
+use std::time::Duration;
+
+struct ActionEntry<K> {
+    key: K,
+    tag: Option<u32>,
+    action: Box<dyn Action>,
+    started: bool,
+    stopped: bool,
+}
+
+struct ManagedTarget<'a> {
+    target: &'a mut dyn ActionTarget,
+    removed: bool,
+}
+
+impl ActionTarget for ManagedTarget<'_> {
+    fn position(&self) -> Position {
+        self.target.position()
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.target.set_position(position);
+    }
+
+    fn rotation(&self) -> Angle {
+        self.target.rotation()
+    }
+
+    fn set_rotation(&mut self, rotation: Angle) {
+        self.target.set_rotation(rotation);
+    }
+
+    fn scale(&self) -> Vector {
+        self.target.scale()
+    }
+
+    fn set_scale(&mut self, scale: Vector) {
+        self.target.set_scale(scale);
+    }
+
+    fn color(&self) -> Color {
+        self.target.color()
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.target.set_color(color);
+    }
+
+    fn is_visible(&self) -> bool {
+        self.target.is_visible()
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.target.set_visible(visible);
+    }
+
+    fn remove_self(&mut self) {
+        self.removed = true;
+        self.target.remove_self();
+    }
+}
+
+pub struct ActionManager<K> {
+    entries: Vec<ActionEntry<K>>,
+    removed: Vec<K>,
+}
+
+impl<K: Copy + PartialEq> ActionManager<K> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    pub fn run(&mut self, key: K, action: impl Action + 'static) {
+        self.push_entry(key, None, Box::new(action));
+    }
+
+    pub fn run_with_tag(&mut self, key: K, tag: u32, action: impl Action + 'static) {
+        self.push_entry(key, Some(tag), Box::new(action));
+    }
+
+    fn push_entry(&mut self, key: K, tag: Option<u32>, action: Box<dyn Action>) {
+        self.entries.push(ActionEntry {
+            key,
+            tag,
+            action,
+            started: false,
+            stopped: false,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|entry| !entry.stopped).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_running(&self, key: K) -> bool {
+        self.entries.iter().any(|entry| !entry.stopped && entry.key == key)
+    }
+
+    pub fn is_running_by_tag(&self, key: K, tag: u32) -> bool {
+        self.entries.iter().any(|entry| !entry.stopped && entry.key == key && entry.tag == Some(tag))
+    }
+
+    pub fn stop(&mut self, key: K) {
+        for entry in self.entries.iter_mut().filter(|entry| entry.key == key) {
+            entry.stopped = true;
+        }
+    }
+
+    pub fn stop_by_tag(&mut self, key: K, tag: u32) {
+        for entry in self.entries.iter_mut().filter(|entry| entry.key == key && entry.tag == Some(tag)) {
+            entry.stopped = true;
+        }
+    }
+
+    pub fn stop_all_by_tag(&mut self, tag: u32) {
+        for entry in self.entries.iter_mut().filter(|entry| entry.tag == Some(tag)) {
+            entry.stopped = true;
+        }
+    }
+
+    pub fn removed(&self) -> &[K] {
+        &self.removed
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.removed.clear();
+    }
+
+    pub fn update(&mut self, delta_time: Duration, provider: &mut impl ActionTargetProvider<K>) -> Vec<(K, Option<u32>)> {
+        let mut finished = Vec::new();
+        self.removed.clear();
+        for entry in &mut self.entries {
+            if entry.stopped || self.removed.contains(&entry.key) {
+                entry.stopped = true;
+                continue;
+            }
+            let mut target = match provider.action_target(entry.key) {
+                Some(target) => ManagedTarget {
+                    target,
+                    removed: false,
+                },
+                None => {
+                    entry.stopped = true;
+                    continue;
+                }
+            };
+            if !entry.started {
+                entry.action.start(&mut target);
+                entry.started = true;
+            }
+            entry.action.update(&mut target, delta_time);
+            if entry.action.is_finished() {
+                entry.stopped = true;
+                finished.push((entry.key, entry.tag));
+            }
+            if target.removed {
+                entry.stopped = true;
+                self.removed.push(entry.key);
+            }
+        }
+        let removed = &self.removed;
+        self.entries.retain(|entry| !entry.stopped && !removed.contains(&entry.key));
+        finished
+    }
+}
+
+impl<K: Copy + PartialEq> Default for ActionManager<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,13 @@
+mod target;
+mod action;
+mod interval;
+mod instant;
+mod composite;
+mod manager;
+
+pub use target::{ActionTarget, ActionTargetProvider, TargetState};
+pub use action::Action;
+pub use interval::{MoveTo, MoveBy, RotateTo, RotateBy, ScaleTo, ScaleBy, TintTo, FadeTo, FadeIn, FadeOut, Wait};
+pub use instant::{CallFunc, Place, SetVisible, RemoveSelf};
+pub use composite::{Sequence, Spawn, Repeat, RepeatForever};
+pub use manager::ActionManager;
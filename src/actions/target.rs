@@ -0,0 +1,163 @@
+use crate::graphics::{NodeContent, NodeId, SceneGraph, SceneNode};
+use tge::prelude::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub trait ActionTarget {
+    fn position(&self) -> Position;
+
+    fn set_position(&mut self, position: Position);
+
+    fn rotation(&self) -> Angle;
+
+    fn set_rotation(&mut self, rotation: Angle);
+
+    fn scale(&self) -> Vector;
+
+    fn set_scale(&mut self, scale: Vector);
+
+    fn color(&self) -> Color;
+
+    fn set_color(&mut self, color: Color);
+
+    fn is_visible(&self) -> bool;
+
+    fn set_visible(&mut self, visible: bool);
+
+    fn remove_self(&mut self) {}
+}
+
+pub trait ActionTargetProvider<K> {
+    fn action_target(&mut self, key: K) -> Option<&mut dyn ActionTarget>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TargetState {
+    pub position: Position,
+    pub rotation: Angle,
+    pub scale: Vector,
+    pub color: Color,
+    pub visible: bool,
+}
+
+impl TargetState {
+    pub fn new() -> Self {
+        Self {
+            position: Position::zero(),
+            rotation: Angle::zero(),
+            scale: Vector::new(1.0, 1.0),
+            color: Color::WHITE,
+            visible: true,
+        }
+    }
+}
+
+impl Default for TargetState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActionTarget for TargetState {
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    fn rotation(&self) -> Angle {
+        self.rotation
+    }
+
+    fn set_rotation(&mut self, rotation: Angle) {
+        self.rotation = rotation;
+    }
+
+    fn scale(&self) -> Vector {
+        self.scale
+    }
+
+    fn set_scale(&mut self, scale: Vector) {
+        self.scale = scale;
+    }
+
+    fn color(&self) -> Color {
+        self.color
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+}
+
+impl ActionTarget for SceneNode {
+    fn position(&self) -> Position {
+        SceneNode::position(self)
+    }
+
+    fn set_position(&mut self, position: Position) {
+        SceneNode::set_position(self, position);
+    }
+
+    fn rotation(&self) -> Angle {
+        SceneNode::rotation(self)
+    }
+
+    fn set_rotation(&mut self, rotation: Angle) {
+        SceneNode::set_rotation(self, rotation);
+    }
+
+    fn scale(&self) -> Vector {
+        SceneNode::scale(self)
+    }
+
+    fn set_scale(&mut self, scale: Vector) {
+        SceneNode::set_scale(self, scale);
+    }
+
+    fn color(&self) -> Color {
+        match self.content() {
+            Some(NodeContent::Sprite(sprite)) => sprite.color(),
+            Some(NodeContent::Animation(animation)) => animation.color(),
+            None => Color::WHITE,
+        }
+    }
+
+    fn set_color(&mut self, color: Color) {
+        match self.content_mut() {
+            Some(NodeContent::Sprite(sprite)) => sprite.set_color(color),
+            Some(NodeContent::Animation(animation)) => animation.set_color(color),
+            None => (),
+        }
+    }
+
+    fn is_visible(&self) -> bool {
+        SceneNode::is_visible(self)
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        SceneNode::set_visible(self, visible);
+    }
+}
+
+impl ActionTargetProvider<NodeId> for SceneGraph {
+    fn action_target(&mut self, key: NodeId) -> Option<&mut dyn ActionTarget> {
+        self.node_mut(key).map(|node| node as &mut dyn ActionTarget)
+    }
+}
+
+impl<K: Hash + Eq, T: ActionTarget> ActionTargetProvider<K> for HashMap<K, T> {
+    fn action_target(&mut self, key: K) -> Option<&mut dyn ActionTarget> {
+        self.get_mut(&key).map(|target| target as &mut dyn ActionTarget)
+    }
+}
@@ -1,31 +1,32 @@
-use super::Frame;
+use super::{Frame, TimeScale, AnimationClock, Transform2D, scale_duration};
+use super::random::random_hash;
 use crate::asset::TextureRefProvider;
 use tge::prelude::*;
 use std::time::Duration;
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
 pub enum PlayMode {
+    #[default]
     Normal,
     Reversed,
     PingPong,
+    ReversedPingPong,
+    Random,
 }
 
-impl Default for PlayMode {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
 pub enum RepeatCount {
+    #[default]
     Infinite,
     Count(usize),
 }
 
-impl Default for RepeatCount {
-    fn default() -> Self {
-        Self::Infinite
-    }
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum FinishBehavior {
+    HoldLastFrame,
+    #[default]
+    ResetToFirstFrame,
+    Hide,
 }
 
 #[derive(Clone)]
@@ -37,6 +38,19 @@ pub struct Animation {
     since_last_frame: Duration,
     repeat_count: RepeatCount,
     play_count: usize,
+    stepped: bool,
+    finish_behavior: FinishBehavior,
+    random_seed: u64,
+    speed: f32,
+    paused: bool,
+    time_scale: Option<TimeScale>,
+    clock: Option<AnimationClock>,
+    phase_offset: Duration,
+    clock_shift: i128,
+    clock_play_count_base: i128,
+    clock_paused_at: Option<Duration>,
+    flip_x: bool,
+    flip_y: bool,
     color: Color,
 }
 
@@ -51,6 +65,19 @@ impl Animation {
             since_last_frame: Duration::new(0, 0),
             repeat_count: RepeatCount::default(),
             play_count: 0,
+            stepped: false,
+            finish_behavior: FinishBehavior::default(),
+            random_seed: 0,
+            speed: 1.0,
+            paused: false,
+            time_scale: None,
+            clock: None,
+            phase_offset: Duration::new(0, 0),
+            clock_shift: 0,
+            clock_play_count_base: 0,
+            clock_paused_at: None,
+            flip_x: false,
+            flip_y: false,
             color: Color::WHITE,
         }
     }
@@ -60,9 +87,14 @@ impl Animation {
     }
 
     pub fn reset(&mut self) {
+        if self.clock.is_some() {
+            self.rebase_clock(0, Duration::new(0, 0), 0);
+            return;
+        }
         self.current_index = 0;
         self.since_last_frame = Duration::new(0, 0);
         self.play_count = 0;
+        self.stepped = false;
     }
 
     pub fn frame_duration(&self) -> Duration {
@@ -70,7 +102,15 @@ impl Animation {
     }
 
     pub fn set_frame_duration(&mut self, frame_duration: Duration) {
+        let (index, since_last_frame, play_count) = self.state();
+        let since_last_frame = match since_last_frame.as_nanos().checked_mul(frame_duration.as_nanos()) {
+            Some(since_last_frame) => since_last_frame.checked_div(self.frame_duration.as_nanos()).unwrap_or(0),
+            None => 0,
+        };
         self.frame_duration = frame_duration;
+        if self.clock.is_some() {
+            self.rebase_clock(index, Duration::from_nanos(since_last_frame as u64), play_count);
+        }
     }
 
     pub fn fps(&self) -> f32 {
@@ -78,7 +118,7 @@ impl Animation {
     }
 
     pub fn set_fps(&mut self, fps: f32) {
-        self.frame_duration = Duration::from_secs_f32(1.0 / fps);
+        self.set_frame_duration(Duration::from_secs_f32(1.0 / fps));
     }
 
     pub fn frames(&self) -> &[Frame] {
@@ -97,8 +137,8 @@ impl Animation {
 
     pub fn one_period_frames_len(&self) -> usize {
         match self.play_mode {
-            PlayMode::Normal | PlayMode::Reversed => self.frames.len(),
-            PlayMode::PingPong => (self.frames.len() * 2 - 2).max(1),
+            PlayMode::Normal | PlayMode::Reversed | PlayMode::Random => self.frames.len(),
+            PlayMode::PingPong | PlayMode::ReversedPingPong => (self.frames.len() * 2 - 2).max(1),
         }
     }
 
@@ -118,28 +158,148 @@ impl Animation {
     }
 
     pub fn current_index(&self) -> usize {
-        self.current_index
+        self.state().0
     }
 
     pub fn set_current_index(&mut self, index: usize) {
-        self.current_index = index % self.one_period_frames_len();
-        self.reset_current_frame();
+        self.set_position(index % self.one_period_frames_len(), Duration::new(0, 0));
     }
 
     pub fn current_frame_index(&self) -> usize {
-        match self.play_mode {
-            PlayMode::Normal=> self.current_index,
-            PlayMode::Reversed => self.frames.len() - self.current_index - 1,
-            PlayMode::PingPong => {
-                if self.current_index < self.frames.len() {
-                    self.current_index
+        let (index, _, play_count) = self.state();
+        self.frame_index_of(index, play_count)
+    }
+
+    fn state(&self) -> (usize, Duration, usize) {
+        let clock = match &self.clock {
+            Some(clock) => clock,
+            None => return (self.current_index, self.since_last_frame, self.play_count),
+        };
+        let frame_duration = self.frame_duration.as_nanos();
+        if frame_duration == 0 {
+            return (0, Duration::new(0, 0), 0);
+        }
+        let period_frames_len = self.one_period_frames_len();
+        let time = self.clock_time(clock);
+        let step = (time / frame_duration) as usize;
+        let play_count = ((step / period_frames_len) as i128 - self.clock_play_count_base).max(0) as usize;
+        if let RepeatCount::Count(repeat_count) = self.repeat_count {
+            if play_count >= repeat_count {
+                let index = if self.finish_behavior == FinishBehavior::HoldLastFrame {
+                    period_frames_len - 1
                 } else {
-                    self.frames.len() * 2 - self.current_index - 2
-                }
+                    0
+                };
+                return (index, Duration::new(0, 0), repeat_count);
+            }
+        }
+        (step % period_frames_len, Duration::from_nanos((time % frame_duration) as u64), play_count)
+    }
+
+    fn clock_time(&self, clock: &AnimationClock) -> u128 {
+        let elapsed = self.clock_paused_at.unwrap_or_else(|| clock.elapsed());
+        (elapsed.as_nanos() as i128 + self.phase_offset.as_nanos() as i128 + self.clock_shift).max(0) as u128
+    }
+
+    fn rebase_clock(&mut self, index: usize, since_last_frame: Duration, play_count: usize) {
+        let clock = match &self.clock {
+            Some(clock) => clock,
+            None => return,
+        };
+        let period_duration = self.period_duration().as_nanos() as i128;
+        if period_duration == 0 {
+            return;
+        }
+        let time = self.clock_time(clock) as i128;
+        let period_count = time / period_duration;
+        let target = period_count * period_duration + (self.frame_duration * index as u32 + since_last_frame).as_nanos() as i128;
+        self.clock_shift += target - time;
+        self.clock_play_count_base = period_count - play_count as i128;
+    }
+
+    fn set_position(&mut self, index: usize, since_last_frame: Duration) {
+        if self.clock.is_some() {
+            let play_count = self.play_count();
+            self.rebase_clock(index, since_last_frame, play_count);
+            return;
+        }
+        self.current_index = index;
+        self.since_last_frame = since_last_frame;
+        self.stepped = true;
+    }
+
+    fn frame_index_of(&self, index: usize, play_count: usize) -> usize {
+        let frames_len = self.frames.len();
+        let ping_pong_index = |index: usize| {
+            if index < frames_len {
+                index
+            } else {
+                frames_len * 2 - index - 2
+            }
+        };
+        match self.play_mode {
+            PlayMode::Normal=> index,
+            PlayMode::Reversed => frames_len - index - 1,
+            PlayMode::PingPong => ping_pong_index(index),
+            PlayMode::ReversedPingPong => frames_len - ping_pong_index(index) - 1,
+            PlayMode::Random => {
+                let step = (play_count as u64).wrapping_mul(frames_len as u64).wrapping_add(index as u64);
+                (random_hash(self.random_seed, step) % frames_len as u64) as usize
             }
         }
     }
 
+    pub fn frame_index_at(&self, time: Duration) -> usize {
+        let frame_duration = self.frame_duration.as_nanos();
+        if frame_duration == 0 {
+            return self.frame_index_of(0, 0);
+        }
+        let step = (time.as_nanos() / frame_duration) as usize;
+        let period_frames_len = self.one_period_frames_len();
+        self.frame_index_of(step % period_frames_len, step / period_frames_len)
+    }
+
+    pub fn period_duration(&self) -> Duration {
+        self.frame_duration * self.one_period_frames_len() as u32
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        let (index, since_last_frame, _) = self.state();
+        self.frame_duration * index as u32 + since_last_frame.min(self.frame_duration)
+    }
+
+    pub fn progress(&self) -> f32 {
+        let period_duration = self.period_duration();
+        if period_duration == Duration::new(0, 0) {
+            return 0.0;
+        }
+        (self.elapsed().as_secs_f32() / period_duration.as_secs_f32()).min(1.0)
+    }
+
+    pub fn seek(&mut self, time: Duration) {
+        let (index, since_last_frame) = self.locate(time);
+        self.set_position(index, since_last_frame);
+    }
+
+    pub fn seek_progress(&mut self, progress: f32) {
+        let period_frames_len = self.one_period_frames_len();
+        let progress = if progress.is_nan() { 0.0 } else { progress.clamp(0.0, 1.0) };
+        let position = progress * period_frames_len as f32;
+        let index = (position.floor() as usize).min(period_frames_len - 1);
+        self.set_position(index, self.frame_duration.mul_f32((position - index as f32).min(1.0)));
+    }
+
+    fn locate(&self, time: Duration) -> (usize, Duration) {
+        let frame_duration = self.frame_duration.as_nanos();
+        if frame_duration == 0 {
+            return (0, Duration::new(0, 0));
+        }
+        let time = time.as_nanos() % (frame_duration * self.one_period_frames_len() as u128);
+        let index = (time / frame_duration) as usize;
+        let since_last_frame = time % frame_duration;
+        (index, Duration::new((since_last_frame / 1_000_000_000) as u64, (since_last_frame % 1_000_000_000) as u32))
+    }
+
     pub fn current_frame(&self) -> &Frame {
         self.frames.get(self.current_frame_index()).expect("wrong current index")
     }
@@ -153,20 +313,129 @@ impl Animation {
     }
 
     pub fn play_count(&self) -> usize {
-        self.play_count
+        self.state().2
     }
 
     pub fn set_play_count(&mut self, play_count: usize) {
+        if self.clock.is_some() {
+            let (index, since_last_frame, _) = self.state();
+            self.rebase_clock(index, since_last_frame, play_count);
+            return;
+        }
         self.play_count = play_count;
     }
 
+    pub fn finish_behavior(&self) -> FinishBehavior {
+        self.finish_behavior
+    }
+
+    pub fn set_finish_behavior(&mut self, finish_behavior: FinishBehavior) {
+        self.finish_behavior = finish_behavior;
+    }
+
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    pub fn set_random_seed(&mut self, random_seed: u64) {
+        self.random_seed = random_seed;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        !(self.finish_behavior == FinishBehavior::Hide && self.is_finished())
+    }
+
     pub fn is_finished(&self) -> bool {
         match self.repeat_count {
             RepeatCount::Infinite => false,
-            RepeatCount::Count(repeat_count) => self.play_count >= repeat_count,
+            RepeatCount::Count(repeat_count) => self.play_count() >= repeat_count,
+        }
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    pub fn pause(&mut self) {
+        if let (Some(clock), None) = (&self.clock, self.clock_paused_at) {
+            self.clock_paused_at = Some(clock.elapsed());
+        }
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        if let (Some(clock), Some(paused_at)) = (&self.clock, self.clock_paused_at) {
+            self.clock_shift -= clock.elapsed().as_nanos() as i128 - paused_at.as_nanos() as i128;
+        }
+        self.clock_paused_at = None;
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn time_scale(&self) -> Option<&TimeScale> {
+        self.time_scale.as_ref()
+    }
+
+    pub fn set_time_scale(&mut self, time_scale: Option<TimeScale>) {
+        self.time_scale = time_scale;
+    }
+
+    pub fn clock(&self) -> Option<&AnimationClock> {
+        self.clock.as_ref()
+    }
+
+    pub fn set_clock(&mut self, clock: Option<AnimationClock>) {
+        if self.clock.is_some() {
+            let (index, since_last_frame, play_count) = self.state();
+            self.current_index = index;
+            self.since_last_frame = since_last_frame;
+            self.play_count = play_count;
+        }
+        self.clock = clock;
+        self.clock_shift = 0;
+        self.clock_play_count_base = 0;
+        self.clock_paused_at = None;
+        if let Some(clock) = &self.clock {
+            if self.paused {
+                self.clock_paused_at = Some(clock.elapsed());
+            }
+            if let Some(period_count) = self.clock_time(clock).checked_div(self.period_duration().as_nanos()) {
+                self.clock_play_count_base = period_count as i128;
+            }
         }
     }
 
+    pub fn phase_offset(&self) -> Duration {
+        self.phase_offset
+    }
+
+    pub fn set_phase_offset(&mut self, phase_offset: Duration) {
+        self.phase_offset = phase_offset;
+    }
+
+    pub fn flip_x(&self) -> bool {
+        self.flip_x
+    }
+
+    pub fn set_flip_x(&mut self, flip_x: bool) {
+        self.flip_x = flip_x;
+    }
+
+    pub fn flip_y(&self) -> bool {
+        self.flip_y
+    }
+
+    pub fn set_flip_y(&mut self, flip_y: bool) {
+        self.flip_y = flip_y;
+    }
+
     pub fn color(&self) -> Color {
         self.color
     }
@@ -176,30 +445,90 @@ impl Animation {
     }
 
     pub fn update(&mut self, delta_time: Duration) {
-        if !self.is_finished() {
-            self.since_last_frame += delta_time;
-            if self.since_last_frame >= self.frame_duration {
-                self.since_last_frame = Duration::new(0, 0);
-                self.current_index += 1;
-                if self.current_index >= self.one_period_frames_len() {
-                    self.current_index = 0;
-                    self.play_count += 1;
-                }
+        if self.clock.is_some() || self.paused || self.is_finished() {
+            return;
+        }
+        let scale = self.speed * self.time_scale.as_ref().map_or(1.0, |time_scale| time_scale.scale());
+        if scale == 0.0 || !scale.is_finite() {
+            return;
+        }
+        if scale < 0.0 && !self.stepped {
+            self.stepped = true;
+            if self.current_index == 0 {
+                self.current_index = self.one_period_frames_len() - 1;
+            }
+        }
+        self.since_last_frame = self.since_last_frame.saturating_add(scale_duration(delta_time, scale));
+        self.skip_periods();
+        while self.since_last_frame >= self.frame_duration && !self.is_finished() {
+            self.since_last_frame -= self.frame_duration;
+            if scale > 0.0 {
+                self.step_forward();
+            } else {
+                self.step_backward();
+            }
+            if self.frame_duration == Duration::new(0, 0) {
+                break;
+            }
+        }
+    }
+
+    fn step_forward(&mut self) {
+        self.stepped = true;
+        if self.current_index + 1 >= self.one_period_frames_len() {
+            self.play_count = self.play_count.saturating_add(1);
+            if !self.holds_last_frame() {
+                self.current_index = 0;
             }
+        } else {
+            self.current_index += 1;
         }
     }
 
-    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform>>) -> GameResult {
-        let frame = self.current_frame();
-        graphics.draw_sprite(
-            provider.texture_ref(&frame.res_name)?,
-            SpriteDrawParams::default()
-                .region(frame.region)
-                .origin(frame.origin)
-                .color(self.color),
-            transform,
-        );
-        Ok(())
+    fn skip_periods(&mut self) {
+        let period_duration = self.period_duration().as_nanos();
+        if period_duration == 0 {
+            return;
+        }
+        let periods = self.since_last_frame.as_nanos() / period_duration;
+        let periods = match self.repeat_count {
+            RepeatCount::Infinite => periods,
+            RepeatCount::Count(repeat_count) => periods.min(repeat_count.saturating_sub(self.play_count + 1) as u128),
+        };
+        if periods == 0 {
+            return;
+        }
+        let since_last_frame = self.since_last_frame.as_nanos() - periods * period_duration;
+        self.since_last_frame = Duration::new((since_last_frame / 1_000_000_000) as u64, (since_last_frame % 1_000_000_000) as u32);
+        self.play_count = self.play_count.saturating_add(periods.min(usize::MAX as u128) as usize);
+        self.stepped = true;
+    }
+
+    fn step_backward(&mut self) {
+        self.stepped = true;
+        if self.current_index == 0 {
+            self.play_count = self.play_count.saturating_add(1);
+            if !self.holds_last_frame() {
+                self.current_index = self.one_period_frames_len() - 1;
+            }
+        } else {
+            self.current_index -= 1;
+        }
+    }
+
+    fn holds_last_frame(&self) -> bool {
+        self.finish_behavior == FinishBehavior::HoldLastFrame && self.is_finished()
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        self.current_frame().bounds_with((self.flip_x, self.flip_y), transform.into())
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        if !self.is_visible() {
+            return Ok(());
+        }
+        self.current_frame().draw_with(graphics, provider, self.color, (self.flip_x, self.flip_y), transform.into())
     }
 }
 
@@ -0,0 +1,192 @@
+use super::ResolutionAdapter;
+use super::transform::Transform2D;
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera2D {
+    position: Position,
+    zoom: f32,
+    rotation: Angle,
+    viewport_size: Size,
+    bounds: Option<Region>,
+    deadzone: Option<Size>,
+    follow_smoothing: f32,
+}
+
+impl Camera2D {
+    pub fn new(viewport_size: impl Into<Size>) -> Self {
+        let viewport_size = viewport_size.into();
+        Self {
+            position: Position::new(viewport_size.width / 2.0, viewport_size.height / 2.0),
+            zoom: 1.0,
+            rotation: Angle::zero(),
+            viewport_size,
+            bounds: None,
+            deadzone: None,
+            follow_smoothing: 0.0,
+        }
+    }
+
+    pub fn by_resolution_adapter(adapter: &impl ResolutionAdapter) -> Self {
+        Self::new(adapter.canvas_size())
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: impl Into<Position>) {
+        self.position = position.into();
+        self.clamp_to_bounds();
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn set_zoom(&mut self, zoom: f32) {
+        assert!(zoom > 0.0, "zoom must be positive");
+        self.zoom = zoom;
+        self.clamp_to_bounds();
+    }
+
+    pub fn rotation(&self) -> Angle {
+        self.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: Angle) {
+        self.rotation = rotation;
+    }
+
+    pub fn viewport_size(&self) -> Size {
+        self.viewport_size
+    }
+
+    pub fn set_viewport_size(&mut self, viewport_size: impl Into<Size>) {
+        self.viewport_size = viewport_size.into();
+        self.clamp_to_bounds();
+    }
+
+    pub fn measure(&mut self, adapter: &impl ResolutionAdapter) {
+        let canvas_size = adapter.canvas_size();
+        if self.viewport_size != canvas_size {
+            self.set_viewport_size(canvas_size);
+        }
+    }
+
+    pub fn bounds(&self) -> Option<Region> {
+        self.bounds
+    }
+
+    pub fn set_bounds(&mut self, bounds: Option<Region>) {
+        self.bounds = bounds;
+        self.clamp_to_bounds();
+    }
+
+    pub fn deadzone(&self) -> Option<Size> {
+        self.deadzone
+    }
+
+    pub fn set_deadzone(&mut self, deadzone: Option<Size>) {
+        self.deadzone = deadzone;
+    }
+
+    pub fn follow_smoothing(&self) -> f32 {
+        self.follow_smoothing
+    }
+
+    pub fn set_follow_smoothing(&mut self, follow_smoothing: f32) {
+        self.follow_smoothing = follow_smoothing.max(0.0);
+    }
+
+    pub fn follow(&mut self, target: impl Into<Position>, delta_time: Duration) {
+        let target = target.into();
+        let mut desired = self.position;
+        match self.deadzone {
+            Some(deadzone) => {
+                let half_width = deadzone.width / 2.0 / self.zoom;
+                let half_height = deadzone.height / 2.0 / self.zoom;
+                if target.x < desired.x - half_width {
+                    desired.x = target.x + half_width;
+                } else if target.x > desired.x + half_width {
+                    desired.x = target.x - half_width;
+                }
+                if target.y < desired.y - half_height {
+                    desired.y = target.y + half_height;
+                } else if target.y > desired.y + half_height {
+                    desired.y = target.y - half_height;
+                }
+            }
+            None => desired = target,
+        }
+        let factor = if self.follow_smoothing > 0.0 {
+            1.0 - (-self.follow_smoothing * delta_time.as_secs_f32()).exp()
+        } else {
+            1.0
+        };
+        self.position = Position::new(
+            self.position.x + (desired.x - self.position.x) * factor,
+            self.position.y + (desired.y - self.position.y) * factor,
+        );
+        self.clamp_to_bounds();
+    }
+
+    fn clamp_to_bounds(&mut self) {
+        if let Some(bounds) = self.bounds {
+            let half_width = self.viewport_size.width / 2.0 / self.zoom;
+            let half_height = self.viewport_size.height / 2.0 / self.zoom;
+            self.position.x = clamp_axis(self.position.x, bounds.x, bounds.width, half_width);
+            self.position.y = clamp_axis(self.position.y, bounds.y, bounds.height, half_height);
+        }
+    }
+
+    pub fn transform(&self) -> Transform2D {
+        Transform2D::default()
+            .translate((-self.position.x, -self.position.y))
+            .rotate(Angle::radians(-self.rotation.radians_value()))
+            .scale((self.zoom, self.zoom))
+            .translate((self.viewport_size.width / 2.0, self.viewport_size.height / 2.0))
+    }
+
+    pub fn visible_region(&self) -> Region {
+        let width = self.viewport_size.width;
+        let height = self.viewport_size.height;
+        let corners = [
+            self.convert_to_world_position((0.0, 0.0)),
+            self.convert_to_world_position((width, 0.0)),
+            self.convert_to_world_position((width, height)),
+            self.convert_to_world_position((0.0, height)),
+        ];
+        let min_x = corners.iter().map(|corner| corner.x).fold(f32::INFINITY, f32::min);
+        let min_y = corners.iter().map(|corner| corner.y).fold(f32::INFINITY, f32::min);
+        let max_x = corners.iter().map(|corner| corner.x).fold(f32::NEG_INFINITY, f32::max);
+        let max_y = corners.iter().map(|corner| corner.y).fold(f32::NEG_INFINITY, f32::max);
+        Region::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
+    pub fn convert_to_world_position(&self, canvas_position: impl Into<Position>) -> Position {
+        let inverse = self.transform().inverse().unwrap_or_default();
+        inverse.transform_position(canvas_position)
+    }
+
+    pub fn convert_to_canvas_position(&self, world_position: impl Into<Position>) -> Position {
+        self.transform().transform_position(world_position)
+    }
+
+    pub fn convert_window_to_world_position(&self, adapter: &impl ResolutionAdapter, window_position: impl Into<LogicalPosition>) -> Position {
+        self.convert_to_world_position(adapter.convert_to_canvas_position(window_position))
+    }
+
+    pub fn convert_world_to_window_position(&self, adapter: &impl ResolutionAdapter, world_position: impl Into<Position>) -> LogicalPosition {
+        adapter.convert_to_window_position(self.convert_to_canvas_position(world_position))
+    }
+}
+
+fn clamp_axis(position: f32, bounds_start: f32, bounds_len: f32, half_view_len: f32) -> f32 {
+    if bounds_len <= half_view_len * 2.0 {
+        bounds_start + bounds_len / 2.0
+    } else {
+        position.clamp(bounds_start + half_view_len, bounds_start + bounds_len - half_view_len)
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct AnimationClock {
+    elapsed: Arc<AtomicU64>,
+}
+
+impl AnimationClock {
+    pub fn new() -> Self {
+        Self {
+            elapsed: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed.load(Ordering::Relaxed))
+    }
+
+    pub fn set_elapsed(&self, elapsed: Duration) {
+        self.elapsed.store(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn reset(&self) {
+        self.elapsed.store(0, Ordering::Relaxed);
+    }
+
+    pub fn update(&self, delta_time: Duration) {
+        self.elapsed.fetch_add(delta_time.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+impl Default for AnimationClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
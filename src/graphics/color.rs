@@ -0,0 +1,24 @@
+use tge::prelude::*;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub(crate) enum HexColorOrder {
+    Rgba,
+    Argb,
+}
+
+pub(crate) fn parse_hex_color(hex: &str, order: HexColorOrder) -> Option<Color> {
+    let digits = hex.chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as f32))
+        .collect::<Option<Vec<f32>>>()?;
+    let channels: Vec<f32> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit / 15.0).collect(),
+        6 | 8 => digits.chunks(2).map(|pair| (pair[0] * 16.0 + pair[1]) / 255.0).collect(),
+        _ => return None,
+    };
+    match (order, channels.as_slice()) {
+        (_, [red, green, blue]) => Some(Color::new(*red, *green, *blue, 1.0)),
+        (HexColorOrder::Rgba, [red, green, blue, alpha]) => Some(Color::new(*red, *green, *blue, *alpha)),
+        (HexColorOrder::Argb, [alpha, red, green, blue]) => Some(Color::new(*red, *green, *blue, *alpha)),
+        _ => None,
+    }
+}
@@ -0,0 +1,47 @@
+use super::{Drawable, ResolutionAdapter, Transform2D};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Culler {
+    view: Region,
+}
+
+impl Culler {
+    pub fn new(view: impl Into<Region>) -> Self {
+        Self {
+            view: view.into(),
+        }
+    }
+
+    pub fn by_canvas_viewport(adapter: &impl ResolutionAdapter, graphics: &mut Graphics) -> Self {
+        let canvas_viewport = adapter.canvas_viewport(graphics);
+        Self::new((0.0, 0.0, canvas_viewport.width, canvas_viewport.height))
+    }
+
+    pub fn view(&self) -> Region {
+        self.view
+    }
+
+    pub fn set_view(&mut self, view: impl Into<Region>) {
+        self.view = view.into();
+    }
+
+    pub fn is_visible(&self, bounds: impl Into<Region>) -> bool {
+        let bounds = bounds.into();
+        bounds.x < self.view.x + self.view.width
+            && bounds.x + bounds.width > self.view.x
+            && bounds.y < self.view.y + self.view.height
+            && bounds.y + bounds.height > self.view.y
+    }
+
+    pub fn draw<'a>(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, drawable: impl Into<Drawable<'a>>, transform: impl Into<Option<Transform2D>>) -> GameResult<bool> {
+        let drawable = drawable.into();
+        let transform = transform.into();
+        if !self.is_visible(drawable.bounds(transform)) {
+            return Ok(false);
+        }
+        drawable.draw(graphics, provider, transform)?;
+        Ok(true)
+    }
+}
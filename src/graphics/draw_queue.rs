@@ -0,0 +1,163 @@
+use super::{Sprite, Animation, NinePatch, TiledSprite, Culler, Transform2D};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy)]
+pub enum Drawable<'a> {
+    Sprite(&'a Sprite),
+    Animation(&'a Animation),
+    NinePatch(&'a NinePatch),
+    TiledSprite(&'a TiledSprite),
+}
+
+impl<'a> Drawable<'a> {
+    pub fn res_name(&self) -> &'a str {
+        match self {
+            Self::Sprite(sprite) => sprite.res_name(),
+            Self::Animation(animation) => animation.current_frame().res_name(),
+            Self::NinePatch(nine_patch) => nine_patch.res_name(),
+            Self::TiledSprite(tiled_sprite) => tiled_sprite.res_name(),
+        }
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        match self {
+            Self::Sprite(sprite) => sprite.bounds(transform),
+            Self::Animation(animation) => animation.bounds(transform),
+            Self::NinePatch(nine_patch) => nine_patch.bounds(transform),
+            Self::TiledSprite(tiled_sprite) => tiled_sprite.bounds(transform),
+        }
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        match self {
+            Self::Sprite(sprite) => sprite.draw(graphics, provider, transform),
+            Self::Animation(animation) => animation.draw(graphics, provider, transform),
+            Self::NinePatch(nine_patch) => nine_patch.draw(graphics, provider, transform),
+            Self::TiledSprite(tiled_sprite) => tiled_sprite.draw(graphics, provider, transform),
+        }
+    }
+}
+
+impl<'a> From<&'a Sprite> for Drawable<'a> {
+    fn from(sprite: &'a Sprite) -> Self {
+        Self::Sprite(sprite)
+    }
+}
+
+impl<'a> From<&'a Animation> for Drawable<'a> {
+    fn from(animation: &'a Animation) -> Self {
+        Self::Animation(animation)
+    }
+}
+
+impl<'a> From<&'a NinePatch> for Drawable<'a> {
+    fn from(nine_patch: &'a NinePatch) -> Self {
+        Self::NinePatch(nine_patch)
+    }
+}
+
+impl<'a> From<&'a TiledSprite> for Drawable<'a> {
+    fn from(tiled_sprite: &'a TiledSprite) -> Self {
+        Self::TiledSprite(tiled_sprite)
+    }
+}
+
+struct DrawItem<'a> {
+    drawable: Drawable<'a>,
+    layer: i32,
+    depth: f32,
+    transform: Option<Transform2D>,
+}
+
+pub struct DrawQueue<'a> {
+    items: Vec<DrawItem<'a>>,
+    group_by_texture: bool,
+    culler: Option<Culler>,
+}
+
+impl<'a> DrawQueue<'a> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            group_by_texture: false,
+            culler: None,
+        }
+    }
+
+    pub fn group_by_texture(&self) -> bool {
+        self.group_by_texture
+    }
+
+    pub fn set_group_by_texture(&mut self, group_by_texture: bool) {
+        self.group_by_texture = group_by_texture;
+    }
+
+    pub fn culler(&self) -> Option<&Culler> {
+        self.culler.as_ref()
+    }
+
+    pub fn set_culler(&mut self, culler: Option<Culler>) {
+        self.culler = culler;
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    pub fn push(&mut self, drawable: impl Into<Drawable<'a>>, layer: i32, depth: f32, transform: impl Into<Option<Transform2D>>) {
+        self.items.push(DrawItem {
+            drawable: drawable.into(),
+            layer,
+            depth,
+            transform: transform.into(),
+        });
+    }
+
+    pub fn push_y_sorted(&mut self, drawable: impl Into<Drawable<'a>>, layer: i32, position: impl Into<Position>, transform: impl Into<Option<Transform2D>>) {
+        self.push(drawable, layer, position.into().y, transform);
+    }
+
+    pub fn sort(&mut self) {
+        let group_by_texture = self.group_by_texture;
+        self.items.sort_by(|a, b| {
+            a.layer.cmp(&b.layer)
+                .then_with(|| a.depth.total_cmp(&b.depth))
+                .then_with(|| {
+                    if group_by_texture {
+                        a.drawable.res_name().cmp(b.drawable.res_name())
+                    } else {
+                        Ordering::Equal
+                    }
+                })
+        });
+    }
+
+    pub fn flush(&mut self, graphics: &mut Graphics, provider: &impl TextureRefProvider) -> GameResult {
+        self.sort();
+        let culler = self.culler.as_ref();
+        let result = self.items.iter()
+            .filter(|item| match culler {
+                Some(culler) => culler.is_visible(item.drawable.bounds(item.transform)),
+                None => true,
+            })
+            .try_for_each(|item| item.drawable.draw(graphics, provider, item.transform));
+        self.items.clear();
+        result
+    }
+}
+
+impl<'a> Default for DrawQueue<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
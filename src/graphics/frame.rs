@@ -1,3 +1,5 @@
+use super::{Anchor, Origin};
+use super::transform::{Transform2D, concat_transform, to_transform, transform_bounds};
 use crate::asset::TextureRefProvider;
 use tge::prelude::*;
 
@@ -5,25 +7,35 @@ use tge::prelude::*;
 pub struct Frame {
     pub(crate) res_name: String,
     pub(crate) region: Region,
-    pub(crate) origin: Position,
+    pub(crate) origin: Origin,
+    pub(crate) source_size: Option<Size>,
+    pub(crate) trim_offset: Position,
+    pub(crate) rotated: bool,
+    pub(crate) flip_x: bool,
+    pub(crate) flip_y: bool,
 }
 
 impl Frame {
-    pub fn new(res_name: impl Into<String>, region: impl Into<Region>, origin: impl Into<Position>) -> Self {
+    pub fn new(res_name: impl Into<String>, region: impl Into<Region>, origin: impl Into<Origin>) -> Self {
         Self {
             res_name: res_name.into(),
             region: region.into(),
             origin: origin.into(),
+            source_size: None,
+            trim_offset: Position::zero(),
+            rotated: false,
+            flip_x: false,
+            flip_y: false,
         }
     }
 
-    pub fn by_texture_ref(provider: &impl TextureRefProvider, res_name: impl Into<String>, origin: impl Into<Position>) -> GameResult<Self> {
+    pub fn by_texture_ref(provider: &impl TextureRefProvider, res_name: impl Into<String>, origin: impl Into<Origin>) -> GameResult<Self> {
         let res_name = res_name.into();
         let region = get_texture_region(provider, &res_name)?;
         Ok(Self::new(res_name, region, origin))
     }
 
-    pub fn split(res_name: impl AsRef<str>, region: impl Into<Region>, cols: usize, rows: usize, origin: impl Into<Position>) -> Vec<Self> {
+    pub fn split(res_name: impl AsRef<str>, region: impl Into<Region>, cols: usize, rows: usize, origin: impl Into<Origin>) -> Vec<Self> {
         let res_name = res_name.as_ref();
         let region = region.into();
         let origin = origin.into();
@@ -39,7 +51,7 @@ impl Frame {
         sheet
     }
 
-    pub fn split_by_texture_ref(provider: &impl TextureRefProvider, res_name: impl AsRef<str>, cols: usize, rows: usize, origin: impl Into<Position>) -> GameResult<Vec<Self>> {
+    pub fn split_by_texture_ref(provider: &impl TextureRefProvider, res_name: impl AsRef<str>, cols: usize, rows: usize, origin: impl Into<Origin>) -> GameResult<Vec<Self>> {
         let region = get_texture_region(provider, &res_name)?;
         Ok(Self::split(res_name, region, cols, rows, origin))
     }
@@ -61,12 +73,131 @@ impl Frame {
     }
 
     pub fn origin(&self) -> Position {
-        self.origin
+        self.origin.resolve(self.size())
     }
 
-    pub fn set_origin(&mut self, origin: impl Into<Position>) {
+    pub fn set_origin(&mut self, origin: impl Into<Origin>) {
         self.origin = origin.into();
     }
+
+    pub fn anchor(&self) -> Option<Anchor> {
+        match self.origin {
+            Origin::Anchor(anchor) => Some(anchor),
+            Origin::Pixels(_) => None,
+        }
+    }
+
+    pub fn set_anchor(&mut self, anchor: impl Into<Anchor>) {
+        self.origin = Origin::Anchor(anchor.into());
+    }
+
+    pub fn source_size(&self) -> Option<Size> {
+        self.source_size
+    }
+
+    pub fn set_source_size(&mut self, source_size: impl Into<Option<Size>>) {
+        self.source_size = source_size.into();
+    }
+
+    pub fn trim_offset(&self) -> Position {
+        self.trim_offset
+    }
+
+    pub fn set_trim_offset(&mut self, trim_offset: impl Into<Position>) {
+        self.trim_offset = trim_offset.into();
+    }
+
+    pub fn is_trimmed(&self) -> bool {
+        self.source_size.is_some()
+    }
+
+    pub fn is_rotated(&self) -> bool {
+        self.rotated
+    }
+
+    pub fn set_rotated(&mut self, rotated: bool) {
+        self.rotated = rotated;
+    }
+
+    pub fn size(&self) -> Size {
+        if let Some(source_size) = self.source_size {
+            return source_size;
+        }
+        self.trimmed_size()
+    }
+
+    pub fn trimmed_size(&self) -> Size {
+        if self.rotated {
+            Size::new(self.region.height, self.region.width)
+        } else {
+            Size::new(self.region.width, self.region.height)
+        }
+    }
+
+    pub fn flip_x(&self) -> bool {
+        self.flip_x
+    }
+
+    pub fn set_flip_x(&mut self, flip_x: bool) {
+        self.flip_x = flip_x;
+    }
+
+    pub fn flip_y(&self) -> bool {
+        self.flip_y
+    }
+
+    pub fn set_flip_y(&mut self, flip_y: bool) {
+        self.flip_y = flip_y;
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        self.bounds_with((false, false), transform.into())
+    }
+
+    pub(crate) fn bounds_with(&self, flip: (bool, bool), transform: Option<Transform2D>) -> Region {
+        let origin = self.origin();
+        let size = self.trimmed_size();
+        let mut x = self.trim_offset.x - origin.x;
+        let mut y = self.trim_offset.y - origin.y;
+        if self.flip_x != flip.0 {
+            x = -(x + size.width);
+        }
+        if self.flip_y != flip.1 {
+            y = -(y + size.height);
+        }
+        transform_bounds(transform, Region::new(x, y, size.width, size.height))
+    }
+
+    pub(crate) fn draw_with(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, color: Color, flip: (bool, bool), transform: Option<Transform2D>) -> GameResult {
+        let flip_x = self.flip_x != flip.0;
+        let flip_y = self.flip_y != flip.1;
+        let origin = self.origin();
+        let mut origin = Position::new(origin.x - self.trim_offset.x, origin.y - self.trim_offset.y);
+        let mut local = None;
+        if self.rotated {
+            origin = Position::new(self.region.width - origin.y, origin.x);
+            local = Some(Transform2D::default().rotate(Angle::degrees(-90.0)));
+        }
+        if flip_x || flip_y {
+            local = Some(local.unwrap_or_default().scale((
+                if flip_x { -1.0 } else { 1.0 },
+                if flip_y { -1.0 } else { 1.0 },
+            )));
+        }
+        let transform = match local {
+            Some(local) => Some(concat_transform(local, transform)),
+            None => transform,
+        };
+        graphics.draw_sprite(
+            provider.texture_ref(&self.res_name)?,
+            SpriteDrawParams::default()
+                .region(self.region)
+                .origin(origin)
+                .color(color),
+            to_transform(transform),
+        );
+        Ok(())
+    }
 }
 
 pub fn get_texture_region(provider: &impl TextureRefProvider, res_name: impl AsRef<str>) -> GameResult<Region> {
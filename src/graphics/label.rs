@@ -0,0 +1,280 @@
+use super::TextMetrics;
+use super::transform::{Transform2D, concat_transform, transform_bounds};
+use crate::asset::FontProvider;
+use tge::prelude::*;
+use std::mem;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum HorizontalAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl HorizontalAlign {
+    pub(super) fn factor(&self) -> f32 {
+        match self {
+            Self::Left => 0.0,
+            Self::Center => 0.5,
+            Self::Right => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl VerticalAlign {
+    pub(super) fn factor(&self) -> f32 {
+        match self {
+            Self::Top => 0.0,
+            Self::Middle => 0.5,
+            Self::Bottom => 1.0,
+        }
+    }
+}
+
+struct LabelLine {
+    text: String,
+    width: f32,
+}
+
+impl LabelLine {
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            width: 0.0,
+        }
+    }
+}
+
+struct LabelLayout {
+    lines: Vec<LabelLine>,
+    size: Size,
+    line_advance: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    font_name: String,
+    text: String,
+    text_size: f32,
+    color: Color,
+    max_width: Option<f32>,
+    word_wrap: bool,
+    horizontal_align: HorizontalAlign,
+    vertical_align: VerticalAlign,
+    line_spacing: f32,
+    metrics: TextMetrics,
+}
+
+impl Label {
+    pub fn new(font_name: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            font_name: font_name.into(),
+            text: text.into(),
+            text_size: 16.0,
+            color: Color::WHITE,
+            max_width: None,
+            word_wrap: true,
+            horizontal_align: HorizontalAlign::default(),
+            vertical_align: VerticalAlign::default(),
+            line_spacing: 1.0,
+            metrics: TextMetrics::default(),
+        }
+    }
+
+    pub fn font_name(&self) -> &str {
+        &self.font_name
+    }
+
+    pub fn set_font_name(&mut self, font_name: impl Into<String>) {
+        self.font_name = font_name.into();
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    pub fn set_text_size(&mut self, text_size: f32) {
+        self.text_size = text_size;
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        self.color = color.into();
+    }
+
+    pub fn max_width(&self) -> Option<f32> {
+        self.max_width
+    }
+
+    pub fn set_max_width(&mut self, max_width: Option<f32>) {
+        self.max_width = max_width;
+    }
+
+    pub fn is_word_wrap(&self) -> bool {
+        self.word_wrap
+    }
+
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.word_wrap = word_wrap;
+    }
+
+    pub fn horizontal_align(&self) -> HorizontalAlign {
+        self.horizontal_align
+    }
+
+    pub fn set_horizontal_align(&mut self, horizontal_align: HorizontalAlign) {
+        self.horizontal_align = horizontal_align;
+    }
+
+    pub fn vertical_align(&self) -> VerticalAlign {
+        self.vertical_align
+    }
+
+    pub fn set_vertical_align(&mut self, vertical_align: VerticalAlign) {
+        self.vertical_align = vertical_align;
+    }
+
+    pub fn set_align(&mut self, horizontal_align: HorizontalAlign, vertical_align: VerticalAlign) {
+        self.horizontal_align = horizontal_align;
+        self.vertical_align = vertical_align;
+    }
+
+    pub fn line_spacing(&self) -> f32 {
+        self.line_spacing
+    }
+
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.line_spacing = line_spacing;
+    }
+
+    pub fn metrics(&self) -> TextMetrics {
+        self.metrics
+    }
+
+    pub fn set_metrics(&mut self, metrics: TextMetrics) {
+        self.metrics = metrics;
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.layout().lines.into_iter().map(|line| line.text).collect()
+    }
+
+    pub fn measure(&self) -> Size {
+        self.layout().size
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        let size = self.measure();
+        let origin = self.origin(size);
+        transform_bounds(transform.into(), Region::new(-origin.x, -origin.y, size.width, size.height))
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl FontProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let transform = transform.into();
+        let font = provider.font(&self.font_name)?;
+        let layout = self.layout();
+        let origin = self.origin(layout.size);
+        for (index, line) in layout.lines.iter().enumerate() {
+            if line.text.is_empty() {
+                continue;
+            }
+            let x = (layout.size.width - line.width) * self.horizontal_align.factor() - origin.x;
+            let y = index as f32 * layout.line_advance - origin.y;
+            graphics.draw_text(
+                font,
+                &line.text,
+                TextDrawParams::default()
+                    .text_size(self.text_size)
+                    .color(self.color),
+                Transform::from(concat_transform(Transform2D::default().translate((x, y)), transform)),
+            );
+        }
+        Ok(())
+    }
+
+    fn origin(&self, size: Size) -> Position {
+        Position::new(size.width * self.horizontal_align.factor(), size.height * self.vertical_align.factor())
+    }
+
+    fn layout(&self) -> LabelLayout {
+        let advance = |c: char| self.metrics.char_advance(c, self.text_size);
+        let wrap_width = if self.word_wrap { self.max_width } else { None };
+        let lines: Vec<LabelLine> = self.text
+            .split('\n')
+            .flat_map(|paragraph| wrap_paragraph(paragraph, wrap_width, &advance))
+            .collect();
+        let line_height = self.metrics.scaled_line_height(self.text_size);
+        let line_advance = line_height * self.line_spacing;
+        let widest = lines.iter().map(|line| line.width).fold(0.0, f32::max);
+        let height = if lines.is_empty() {
+            0.0
+        } else {
+            line_advance * (lines.len() - 1) as f32 + line_height
+        };
+        LabelLayout {
+            size: Size::new(self.max_width.unwrap_or(widest).max(widest), height),
+            lines,
+            line_advance,
+        }
+    }
+}
+
+fn measure_text(text: &str, advance: &impl Fn(char) -> f32) -> f32 {
+    text.chars().map(advance).sum()
+}
+
+fn wrap_paragraph(paragraph: &str, wrap_width: Option<f32>, advance: &impl Fn(char) -> f32) -> Vec<LabelLine> {
+    let wrap_width = match wrap_width {
+        Some(wrap_width) => wrap_width,
+        None => return vec![LabelLine { text: paragraph.to_owned(), width: measure_text(paragraph, advance) }],
+    };
+    let space_width = advance(' ');
+    let mut lines = Vec::new();
+    let mut line = LabelLine::new();
+    for (index, word) in paragraph.split(' ').enumerate() {
+        let word_width = measure_text(word, advance);
+        if index > 0 && !line.text.is_empty() {
+            if line.width + space_width + word_width > wrap_width {
+                lines.push(mem::replace(&mut line, LabelLine::new()));
+            } else {
+                line.text.push(' ');
+                line.width += space_width;
+            }
+        }
+        if word_width <= wrap_width {
+            line.text.push_str(word);
+            line.width += word_width;
+            continue;
+        }
+        for c in word.chars() {
+            let char_width = advance(c);
+            if !line.text.is_empty() && line.width + char_width > wrap_width {
+                lines.push(mem::replace(&mut line, LabelLine::new()));
+            }
+            line.text.push(c);
+            line.width += char_width;
+        }
+    }
+    lines.push(line);
+    lines
+}
@@ -1,9 +1,50 @@
+pub(crate) mod transform;
+pub(crate) mod random;
+pub(crate) mod color;
+mod origin;
 mod frame;
+mod sheet;
 mod sprite;
+mod nine_patch;
+mod tiled_sprite;
+mod parallax;
+mod shape;
+mod text_metrics;
+mod label;
+mod rich_text;
+mod rich_label;
+mod typewriter;
 mod animation;
+mod time_scale;
+mod clock;
+mod draw_queue;
+mod culling;
+mod scene;
+mod camera;
+mod shake;
 mod resolution;
 
+pub use origin::{Anchor, Origin};
 pub use frame::{Frame, get_texture_region};
+pub use sheet::{SheetCell, SheetOrder, SheetGrid};
 pub use sprite::Sprite;
-pub use animation::{PlayMode, RepeatCount, Animation};
+pub use nine_patch::{Insets, NinePatchMode, NinePatch};
+pub use tiled_sprite::{TileWrap, TiledSprite};
+pub use parallax::{ParallaxFit, ParallaxLayer, ParallaxBackground};
+pub use shape::{ShapeStyle, Shape};
+pub use text_metrics::TextMetrics;
+pub use label::{HorizontalAlign, VerticalAlign, Label};
+pub use rich_text::{RichStyle, RichSpan, RichText};
+pub use rich_label::RichLabel;
+pub use typewriter::{TypewriterFunc, Typewriter};
+pub use animation::{PlayMode, RepeatCount, FinishBehavior, Animation};
+pub use time_scale::TimeScale;
+pub(crate) use time_scale::scale_duration;
+pub use clock::AnimationClock;
+pub use draw_queue::{Drawable, DrawQueue};
+pub use culling::Culler;
+pub use scene::{NodeId, NodeContent, SceneNode, SceneGraph};
+pub use transform::Transform2D;
+pub use camera::Camera2D;
+pub use shake::CameraShake;
 pub use resolution::{ResolutionPolicy, ResolutionAdapter, CanvasResolutionAdapter, TransformResolutionAdapter};
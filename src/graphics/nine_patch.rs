@@ -0,0 +1,239 @@
+use super::Origin;
+use super::transform::{Transform2D, concat_transform, transform_bounds};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Insets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Insets {
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    pub fn all(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Insets {
+    fn from((left, top, right, bottom): (f32, f32, f32, f32)) -> Self {
+        Self::new(left, top, right, bottom)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum NinePatchMode {
+    #[default]
+    Stretch,
+    Tile,
+}
+
+#[derive(Clone)]
+pub struct NinePatch {
+    res_name: String,
+    region: Region,
+    insets: Insets,
+    size: Size,
+    mode: NinePatchMode,
+    origin: Origin,
+    color: Color,
+}
+
+impl NinePatch {
+    pub fn new(res_name: impl Into<String>, region: impl Into<Region>, insets: impl Into<Insets>) -> Self {
+        let region = region.into();
+        Self {
+            res_name: res_name.into(),
+            region,
+            insets: insets.into(),
+            size: Size::new(region.width, region.height),
+            mode: NinePatchMode::default(),
+            origin: Origin::default(),
+            color: Color::WHITE,
+        }
+    }
+
+    pub fn by_nine_patch_markers(res_name: impl Into<String>, image_size: Size<u32>, pixels: &[u8]) -> GameResult<Self> {
+        let width = image_size.width as usize;
+        let height = image_size.height as usize;
+        if width < 3 || height < 3 || pixels.len() < width * height * 4 {
+            return Err(GameError::RuntimeError("invalid nine patch image".into()));
+        }
+        let is_marker = |x: usize, y: usize| {
+            let index = (y * width + x) * 4;
+            pixels[index] == 0 && pixels[index + 1] == 0 && pixels[index + 2] == 0 && pixels[index + 3] == 255
+        };
+        let (left, right) = find_marker_span((1..width - 1).map(|x| is_marker(x, 0)))
+            .ok_or_else(|| GameError::RuntimeError("no horizontal nine patch marker".into()))?;
+        let (top, bottom) = find_marker_span((1..height - 1).map(|y| is_marker(0, y)))
+            .ok_or_else(|| GameError::RuntimeError("no vertical nine patch marker".into()))?;
+        let inner_width = (width - 2) as f32;
+        let inner_height = (height - 2) as f32;
+        Ok(Self::new(
+            res_name,
+            Region::new(1.0, 1.0, inner_width, inner_height),
+            Insets::new(left as f32, top as f32, inner_width - right as f32, inner_height - bottom as f32),
+        ))
+    }
+
+    pub fn res_name(&self) -> &str {
+        &self.res_name
+    }
+
+    pub fn set_res_name(&mut self, res_name: impl Into<String>) {
+        self.res_name = res_name.into();
+    }
+
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    pub fn set_region(&mut self, region: impl Into<Region>) {
+        self.region = region.into();
+    }
+
+    pub fn insets(&self) -> Insets {
+        self.insets
+    }
+
+    pub fn set_insets(&mut self, insets: impl Into<Insets>) {
+        self.insets = insets.into();
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: impl Into<Size>) {
+        self.size = size.into();
+    }
+
+    pub fn mode(&self) -> NinePatchMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: NinePatchMode) {
+        self.mode = mode;
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin.resolve(self.size)
+    }
+
+    pub fn set_origin(&mut self, origin: impl Into<Origin>) {
+        self.origin = origin.into();
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        self.color = color.into();
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        let origin = self.origin();
+        transform_bounds(transform.into(), Region::new(-origin.x, -origin.y, self.size.width, self.size.height))
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let transform = transform.into();
+        let origin = self.origin();
+        let (left, right) = fit_insets(self.insets.left, self.insets.right, self.size.width);
+        let (top, bottom) = fit_insets(self.insets.top, self.insets.bottom, self.size.height);
+        let region = self.region;
+        let source_xs = [region.x, region.x + self.insets.left, region.x + region.width - self.insets.right, region.x + region.width];
+        let source_ys = [region.y, region.y + self.insets.top, region.y + region.height - self.insets.bottom, region.y + region.height];
+        let target_xs = [0.0, left, self.size.width - right, self.size.width];
+        let target_ys = [0.0, top, self.size.height - bottom, self.size.height];
+        let tile = self.mode == NinePatchMode::Tile;
+        for row in 0..3 {
+            let segments_y = segments(source_ys[row], source_ys[row + 1] - source_ys[row], target_ys[row], target_ys[row + 1] - target_ys[row], tile && row == 1);
+            for col in 0..3 {
+                let segments_x = segments(source_xs[col], source_xs[col + 1] - source_xs[col], target_xs[col], target_xs[col + 1] - target_xs[col], tile && col == 1);
+                for segment_y in &segments_y {
+                    for segment_x in &segments_x {
+                        graphics.draw_sprite(
+                            provider.texture_ref(&self.res_name)?,
+                            SpriteDrawParams::default()
+                                .region((segment_x.source_start, segment_y.source_start, segment_x.source_len, segment_y.source_len))
+                                .color(self.color),
+                            Transform::from(concat_transform(
+                                Transform2D::default()
+                                    .scale((segment_x.target_len / segment_x.source_len, segment_y.target_len / segment_y.source_len))
+                                    .translate((segment_x.target_start - origin.x, segment_y.target_start - origin.y)),
+                                transform,
+                            )),
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Segment {
+    pub source_start: f32,
+    pub source_len: f32,
+    pub target_start: f32,
+    pub target_len: f32,
+}
+
+pub(crate) fn segments(source_start: f32, source_len: f32, target_start: f32, target_len: f32, tile: bool) -> Vec<Segment> {
+    if source_len <= 0.0 || target_len <= 0.0 {
+        return Vec::new();
+    }
+    if !tile {
+        return vec![Segment { source_start, source_len, target_start, target_len }];
+    }
+    let mut segments = Vec::new();
+    let mut offset = 0.0;
+    while offset < target_len {
+        let len = source_len.min(target_len - offset);
+        segments.push(Segment {
+            source_start,
+            source_len: len,
+            target_start: target_start + offset,
+            target_len: len,
+        });
+        offset += source_len;
+    }
+    segments
+}
+
+fn fit_insets(start: f32, end: f32, len: f32) -> (f32, f32) {
+    let total = start + end;
+    if total > len && total > 0.0 {
+        let factor = len / total;
+        (start * factor, end * factor)
+    } else {
+        (start, end)
+    }
+}
+
+fn find_marker_span(markers: impl Iterator<Item = bool>) -> Option<(usize, usize)> {
+    let mut span = None;
+    for (index, marked) in markers.enumerate() {
+        if marked {
+            span = match span {
+                None => Some((index, index + 1)),
+                Some((start, _)) => Some((start, index + 1)),
+            };
+        }
+    }
+    span
+}
@@ -0,0 +1,84 @@
+use tge::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+    Custom(f32, f32),
+}
+
+impl Anchor {
+    pub fn factor(&self) -> Vector {
+        match self {
+            Self::TopLeft => Vector::new(0.0, 0.0),
+            Self::Top => Vector::new(0.5, 0.0),
+            Self::TopRight => Vector::new(1.0, 0.0),
+            Self::Left => Vector::new(0.0, 0.5),
+            Self::Center => Vector::new(0.5, 0.5),
+            Self::Right => Vector::new(1.0, 0.5),
+            Self::BottomLeft => Vector::new(0.0, 1.0),
+            Self::Bottom => Vector::new(0.5, 1.0),
+            Self::BottomRight => Vector::new(1.0, 1.0),
+            Self::Custom(x, y) => Vector::new(*x, *y),
+        }
+    }
+
+    pub fn resolve(&self, size: impl Into<Size>) -> Position {
+        let size = size.into();
+        let factor = self.factor();
+        Position::new(size.width * factor.x, size.height * factor.y)
+    }
+}
+
+impl From<(f32, f32)> for Anchor {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::Custom(x, y)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Origin {
+    Pixels(Position),
+    Anchor(Anchor),
+}
+
+impl Origin {
+    pub fn resolve(&self, size: impl Into<Size>) -> Position {
+        match self {
+            Self::Pixels(position) => *position,
+            Self::Anchor(anchor) => anchor.resolve(size),
+        }
+    }
+}
+
+impl Default for Origin {
+    fn default() -> Self {
+        Self::Pixels(Position::zero())
+    }
+}
+
+impl From<Position> for Origin {
+    fn from(position: Position) -> Self {
+        Self::Pixels(position)
+    }
+}
+
+impl From<(f32, f32)> for Origin {
+    fn from(position: (f32, f32)) -> Self {
+        Self::Pixels(position.into())
+    }
+}
+
+impl From<Anchor> for Origin {
+    fn from(anchor: Anchor) -> Self {
+        Self::Anchor(anchor)
+    }
+}
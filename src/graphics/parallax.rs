@@ -0,0 +1,242 @@
+use super::{Sprite, TiledSprite, TileWrap, ResolutionAdapter, Transform2D};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum ParallaxFit {
+    #[default]
+    None,
+    Width,
+    Height,
+}
+
+#[derive(Clone)]
+pub struct ParallaxLayer {
+    tiled_sprite: TiledSprite,
+    scroll_factor: Vector,
+    repeat_x: bool,
+    repeat_y: bool,
+    offset: Vector,
+    origin: Position,
+    velocity: Vector,
+    auto_scroll: Vector,
+    fit: ParallaxFit,
+}
+
+impl ParallaxLayer {
+    pub fn new(res_name: impl Into<String>, region: impl Into<Region>) -> Self {
+        let region = region.into();
+        Self::by_tiled_sprite(TiledSprite::new(res_name, region, (region.width, region.height)))
+    }
+
+    pub fn by_sprite(sprite: &Sprite) -> Self {
+        let region = sprite.region();
+        let origin = sprite.origin();
+        let mut layer = Self::by_tiled_sprite(TiledSprite::by_sprite(sprite, (region.width, region.height)));
+        layer.repeat_x = false;
+        layer.repeat_y = false;
+        layer.origin = origin;
+        layer
+    }
+
+    pub fn by_tiled_sprite(tiled_sprite: TiledSprite) -> Self {
+        Self {
+            repeat_x: tiled_sprite.wrap_x() == TileWrap::Repeat,
+            repeat_y: tiled_sprite.wrap_y() == TileWrap::Repeat,
+            tiled_sprite,
+            scroll_factor: Vector::new(1.0, 1.0),
+            offset: Vector::zero(),
+            origin: Position::zero(),
+            velocity: Vector::zero(),
+            auto_scroll: Vector::zero(),
+            fit: ParallaxFit::default(),
+        }
+    }
+
+    pub fn tiled_sprite(&self) -> &TiledSprite {
+        &self.tiled_sprite
+    }
+
+    pub fn tiled_sprite_mut(&mut self) -> &mut TiledSprite {
+        &mut self.tiled_sprite
+    }
+
+    pub fn scroll_factor(&self) -> Vector {
+        self.scroll_factor
+    }
+
+    pub fn set_scroll_factor(&mut self, scroll_factor: impl Into<Vector>) {
+        self.scroll_factor = scroll_factor.into();
+    }
+
+    pub fn is_repeat_x(&self) -> bool {
+        self.repeat_x
+    }
+
+    pub fn set_repeat_x(&mut self, repeat_x: bool) {
+        self.repeat_x = repeat_x;
+    }
+
+    pub fn is_repeat_y(&self) -> bool {
+        self.repeat_y
+    }
+
+    pub fn set_repeat_y(&mut self, repeat_y: bool) {
+        self.repeat_y = repeat_y;
+    }
+
+    pub fn set_repeat(&mut self, repeat_x: bool, repeat_y: bool) {
+        self.repeat_x = repeat_x;
+        self.repeat_y = repeat_y;
+    }
+
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: impl Into<Vector>) {
+        self.offset = offset.into();
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn set_origin(&mut self, origin: impl Into<Position>) {
+        self.origin = origin.into();
+    }
+
+    pub fn velocity(&self) -> Vector {
+        self.velocity
+    }
+
+    pub fn set_velocity(&mut self, velocity: impl Into<Vector>) {
+        self.velocity = velocity.into();
+    }
+
+    pub fn fit(&self) -> ParallaxFit {
+        self.fit
+    }
+
+    pub fn set_fit(&mut self, fit: ParallaxFit) {
+        self.fit = fit;
+    }
+
+    pub fn auto_scroll(&self) -> Vector {
+        self.auto_scroll
+    }
+
+    pub fn reset(&mut self) {
+        self.auto_scroll = Vector::zero();
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        let delta_time_f32 = delta_time.as_secs_f32();
+        let region = self.tiled_sprite.region();
+        self.auto_scroll.x += self.velocity.x * delta_time_f32;
+        self.auto_scroll.y += self.velocity.y * delta_time_f32;
+        if self.repeat_x && region.width > 0.0 {
+            self.auto_scroll.x = self.auto_scroll.x.rem_euclid(region.width);
+        }
+        if self.repeat_y && region.height > 0.0 {
+            self.auto_scroll.y = self.auto_scroll.y.rem_euclid(region.height);
+        }
+    }
+
+    pub fn scale(&self, canvas_size: Size) -> f32 {
+        let region = self.tiled_sprite.region();
+        let scale = match self.fit {
+            ParallaxFit::None => 1.0,
+            ParallaxFit::Width => canvas_size.width / region.width,
+            ParallaxFit::Height => canvas_size.height / region.height,
+        };
+        if scale.is_finite() && scale > 0.0 { scale } else { 1.0 }
+    }
+
+    pub fn position(&self, camera_position: impl Into<Position>, canvas_size: Size) -> Position {
+        let camera_position = camera_position.into();
+        let scale = self.scale(canvas_size);
+        Position::new(
+            self.offset.x + (self.auto_scroll.x - self.origin.x) * scale + (canvas_size.width / 2.0 - camera_position.x) * self.scroll_factor.x,
+            self.offset.y + (self.auto_scroll.y - self.origin.y) * scale + (canvas_size.height / 2.0 - camera_position.y) * self.scroll_factor.y,
+        )
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, camera_position: impl Into<Position>, canvas_size: impl Into<Size>) -> GameResult {
+        let canvas_size = canvas_size.into();
+        let scale = self.scale(canvas_size);
+        let position = self.position(camera_position, canvas_size);
+        let mut tiled_sprite = self.tiled_sprite.clone();
+        tiled_sprite.set_origin(Position::zero());
+        tiled_sprite.set_size((canvas_size.width / scale, canvas_size.height / scale));
+        tiled_sprite.set_scroll((position.x / scale, position.y / scale));
+        tiled_sprite.set_wrap_x(if self.repeat_x { TileWrap::Repeat } else { TileWrap::Clamp });
+        tiled_sprite.set_wrap_y(if self.repeat_y { TileWrap::Repeat } else { TileWrap::Clamp });
+        tiled_sprite.draw(graphics, provider, Transform2D::default().scale((scale, scale)))
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ParallaxBackground {
+    layers: Vec<ParallaxLayer>,
+}
+
+impl ParallaxBackground {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn layers(&self) -> &[ParallaxLayer] {
+        &self.layers
+    }
+
+    pub fn layers_mut(&mut self) -> &mut [ParallaxLayer] {
+        &mut self.layers
+    }
+
+    pub fn push(&mut self, layer: ParallaxLayer) {
+        self.layers.push(layer);
+    }
+
+    pub fn insert(&mut self, index: usize, layer: ParallaxLayer) {
+        self.layers.insert(index, layer);
+    }
+
+    pub fn remove(&mut self, index: usize) -> ParallaxLayer {
+        self.layers.remove(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    pub fn reset(&mut self) {
+        for layer in &mut self.layers {
+            layer.reset();
+        }
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        for layer in &mut self.layers {
+            layer.update(delta_time);
+        }
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, adapter: &impl ResolutionAdapter, camera_position: impl Into<Position>) -> GameResult {
+        let canvas_size = adapter.canvas_size();
+        let camera_position = camera_position.into();
+        for layer in &self.layers {
+            layer.draw(graphics, provider, camera_position, canvas_size)?;
+        }
+        Ok(())
+    }
+}
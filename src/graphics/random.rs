@@ -0,0 +1,21 @@
+pub(crate) fn random_hash(seed: u64, value: u64) -> u64 {
+    let mut hash = seed ^ value.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+pub(crate) fn random_unit(seed: u64, value: u64) -> f32 {
+    (random_hash(seed, value) >> 40) as f32 / (1u64 << 24) as f32
+}
+
+pub(crate) fn value_noise(seed: u64, channel: u64, time: f32) -> f32 {
+    let seed = random_hash(seed, channel);
+    let floor = time.floor();
+    let t = time - floor;
+    let index = floor as i64 as u64;
+    let start = random_unit(seed, index) * 2.0 - 1.0;
+    let end = random_unit(seed, index.wrapping_add(1)) * 2.0 - 1.0;
+    let t = t * t * (3.0 - 2.0 * t);
+    start + (end - start) * t
+}
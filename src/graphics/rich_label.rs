@@ -0,0 +1,445 @@
+use super::{Frame, HorizontalAlign, VerticalAlign, RichSpan, RichText, TextMetrics};
+use super::transform::{Transform2D, concat_transform, transform_bounds};
+use crate::asset::{FontProvider, TextureRefProvider};
+use tge::prelude::*;
+use std::mem;
+use std::ops::Range;
+use std::time::Duration;
+
+const IMAGE_GLYPH: char = '\u{fffc}';
+
+enum RichRun {
+    Text {
+        font_name: String,
+        text_size: f32,
+        color: Color,
+    },
+    Image {
+        frame: Frame,
+        scale: f32,
+    },
+    Pause,
+}
+
+struct RichFragment {
+    run: usize,
+    text: String,
+    glyph_start: usize,
+    glyph_count: usize,
+    x: f32,
+    width: f32,
+    height: f32,
+}
+
+pub(super) struct RichLine {
+    fragments: Vec<RichFragment>,
+    pub(super) glyphs: Range<usize>,
+    width: f32,
+    pub(super) height: f32,
+}
+
+impl RichLine {
+    fn new(glyph_start: usize, height: f32) -> Self {
+        Self {
+            fragments: Vec::new(),
+            glyphs: glyph_start..glyph_start,
+            width: 0.0,
+            height,
+        }
+    }
+}
+
+pub(super) struct RichLayout {
+    runs: Vec<RichRun>,
+    pub(super) lines: Vec<RichLine>,
+    pub(super) glyphs: Vec<char>,
+    pub(super) pauses: Vec<(usize, Duration)>,
+    size: Size,
+    line_spacing: f32,
+}
+
+pub(super) struct RichView {
+    pub(super) lines: Range<usize>,
+    pub(super) visible_glyphs: usize,
+    pub(super) size: Size,
+}
+
+impl RichLayout {
+    pub(super) fn lines_height(&self, lines: Range<usize>) -> f32 {
+        match self.lines[lines].split_last() {
+            Some((last, rest)) => rest.iter().map(|line| line.height * self.line_spacing).sum::<f32>() + last.height,
+            None => 0.0,
+        }
+    }
+}
+
+struct RichLayoutBuilder {
+    wrap_width: Option<f32>,
+    empty_line_height: f32,
+    lines: Vec<RichLine>,
+    line: RichLine,
+    word: Vec<RichFragment>,
+    word_width: f32,
+    pending_space: f32,
+    glyphs: Vec<char>,
+    pauses: Vec<(usize, Duration)>,
+}
+
+impl RichLayoutBuilder {
+    fn new(wrap_width: Option<f32>, empty_line_height: f32) -> Self {
+        Self {
+            wrap_width,
+            empty_line_height,
+            lines: Vec::new(),
+            line: RichLine::new(0, empty_line_height),
+            word: Vec::new(),
+            word_width: 0.0,
+            pending_space: 0.0,
+            glyphs: Vec::new(),
+            pauses: Vec::new(),
+        }
+    }
+
+    fn push_char(&mut self, run: usize, c: char, width: f32, height: f32) {
+        self.break_word(width);
+        match self.word.last_mut() {
+            Some(fragment) if fragment.run == run => {
+                fragment.text.push(c);
+                fragment.glyph_count += 1;
+                fragment.width += width;
+                fragment.height = fragment.height.max(height);
+            }
+            _ => self.word.push(RichFragment {
+                run,
+                text: c.to_string(),
+                glyph_start: self.glyphs.len(),
+                glyph_count: 1,
+                x: self.word_width,
+                width,
+                height,
+            }),
+        }
+        self.glyphs.push(c);
+        self.word_width += width;
+    }
+
+    fn push_image(&mut self, run: usize, width: f32, height: f32) {
+        self.break_word(width);
+        self.word.push(RichFragment {
+            run,
+            text: String::new(),
+            glyph_start: self.glyphs.len(),
+            glyph_count: 1,
+            x: self.word_width,
+            width,
+            height,
+        });
+        self.glyphs.push(IMAGE_GLYPH);
+        self.word_width += width;
+    }
+
+    fn break_word(&mut self, width: f32) {
+        if let Some(wrap_width) = self.wrap_width {
+            if !self.word.is_empty() && self.word_width + width > wrap_width {
+                self.end_word();
+            }
+        }
+    }
+
+    fn push_space(&mut self, width: f32) {
+        self.end_word();
+        if !self.line.fragments.is_empty() {
+            self.pending_space += width;
+        }
+    }
+
+    fn push_pause(&mut self, duration: Duration) {
+        self.pauses.push((self.glyphs.len(), duration));
+    }
+
+    fn end_word(&mut self) {
+        let word_glyph_start = match self.word.first() {
+            Some(fragment) => fragment.glyph_start,
+            None => return,
+        };
+        if !self.line.fragments.is_empty() {
+            let overflow = self.wrap_width
+                .map(|wrap_width| self.line.width + self.pending_space + self.word_width > wrap_width)
+                .unwrap_or(false);
+            if overflow {
+                self.end_line();
+            } else {
+                self.line.width += self.pending_space;
+            }
+        }
+        self.pending_space = 0.0;
+        if self.line.fragments.is_empty() {
+            self.line.height = 0.0;
+            self.line.glyphs = word_glyph_start..word_glyph_start;
+        }
+        for mut fragment in self.word.drain(..) {
+            fragment.x += self.line.width;
+            self.line.glyphs.end = fragment.glyph_start + fragment.glyph_count;
+            self.line.height = self.line.height.max(fragment.height);
+            self.line.fragments.push(fragment);
+        }
+        self.line.width += self.word_width;
+        self.word_width = 0.0;
+    }
+
+    fn end_line(&mut self) {
+        self.pending_space = 0.0;
+        let glyph_start = self.glyphs.len() - self.word.iter().map(|fragment| fragment.glyph_count).sum::<usize>();
+        let line = mem::replace(&mut self.line, RichLine::new(glyph_start, self.empty_line_height));
+        self.lines.push(line);
+    }
+
+    fn finish(mut self) -> (Vec<RichLine>, Vec<char>, Vec<(usize, Duration)>) {
+        self.end_word();
+        self.end_line();
+        (self.lines, self.glyphs, self.pauses)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RichLabel {
+    font_name: String,
+    rich_text: RichText,
+    text_size: f32,
+    color: Color,
+    max_width: Option<f32>,
+    word_wrap: bool,
+    horizontal_align: HorizontalAlign,
+    vertical_align: VerticalAlign,
+    line_spacing: f32,
+    metrics: TextMetrics,
+}
+
+impl RichLabel {
+    pub fn new(font_name: impl Into<String>, markup: &str) -> GameResult<Self> {
+        Ok(Self::by_rich_text(font_name, RichText::parse(markup)?))
+    }
+
+    pub fn by_rich_text(font_name: impl Into<String>, rich_text: RichText) -> Self {
+        Self {
+            font_name: font_name.into(),
+            rich_text,
+            text_size: 16.0,
+            color: Color::WHITE,
+            max_width: None,
+            word_wrap: true,
+            horizontal_align: HorizontalAlign::default(),
+            vertical_align: VerticalAlign::default(),
+            line_spacing: 1.0,
+            metrics: TextMetrics::default(),
+        }
+    }
+
+    pub fn font_name(&self) -> &str {
+        &self.font_name
+    }
+
+    pub fn set_font_name(&mut self, font_name: impl Into<String>) {
+        self.font_name = font_name.into();
+    }
+
+    pub fn rich_text(&self) -> &RichText {
+        &self.rich_text
+    }
+
+    pub fn set_rich_text(&mut self, rich_text: RichText) {
+        self.rich_text = rich_text;
+    }
+
+    pub fn set_markup(&mut self, markup: &str) -> GameResult {
+        self.rich_text = RichText::parse(markup)?;
+        Ok(())
+    }
+
+    pub fn text_size(&self) -> f32 {
+        self.text_size
+    }
+
+    pub fn set_text_size(&mut self, text_size: f32) {
+        self.text_size = text_size;
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        self.color = color.into();
+    }
+
+    pub fn max_width(&self) -> Option<f32> {
+        self.max_width
+    }
+
+    pub fn set_max_width(&mut self, max_width: Option<f32>) {
+        self.max_width = max_width;
+    }
+
+    pub fn is_word_wrap(&self) -> bool {
+        self.word_wrap
+    }
+
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.word_wrap = word_wrap;
+    }
+
+    pub fn horizontal_align(&self) -> HorizontalAlign {
+        self.horizontal_align
+    }
+
+    pub fn set_horizontal_align(&mut self, horizontal_align: HorizontalAlign) {
+        self.horizontal_align = horizontal_align;
+    }
+
+    pub fn vertical_align(&self) -> VerticalAlign {
+        self.vertical_align
+    }
+
+    pub fn set_vertical_align(&mut self, vertical_align: VerticalAlign) {
+        self.vertical_align = vertical_align;
+    }
+
+    pub fn set_align(&mut self, horizontal_align: HorizontalAlign, vertical_align: VerticalAlign) {
+        self.horizontal_align = horizontal_align;
+        self.vertical_align = vertical_align;
+    }
+
+    pub fn line_spacing(&self) -> f32 {
+        self.line_spacing
+    }
+
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.line_spacing = line_spacing;
+    }
+
+    pub fn metrics(&self) -> TextMetrics {
+        self.metrics
+    }
+
+    pub fn set_metrics(&mut self, metrics: TextMetrics) {
+        self.metrics = metrics;
+    }
+
+    pub fn measure(&self, provider: &impl TextureRefProvider) -> GameResult<Size> {
+        Ok(self.layout(provider)?.size)
+    }
+
+    pub fn bounds(&self, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult<Region> {
+        let size = self.measure(provider)?;
+        let origin = self.origin(size);
+        Ok(transform_bounds(transform.into(), Region::new(-origin.x, -origin.y, size.width, size.height)))
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &(impl FontProvider + TextureRefProvider), transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let layout = self.layout(provider)?;
+        let view = RichView {
+            lines: 0..layout.lines.len(),
+            visible_glyphs: layout.glyphs.len(),
+            size: layout.size,
+        };
+        self.draw_layout(graphics, provider, &layout, &view, transform.into())
+    }
+
+    pub(super) fn draw_layout(&self, graphics: &mut Graphics, provider: &(impl FontProvider + TextureRefProvider), layout: &RichLayout, view: &RichView, transform: Option<Transform2D>) -> GameResult {
+        let origin = self.origin(view.size);
+        let image_color = Color::new(1.0, 1.0, 1.0, self.color.alpha);
+        let mut top = (view.size.height - layout.lines_height(view.lines.clone())) * self.vertical_align.factor() - origin.y;
+        for line in &layout.lines[view.lines.clone()] {
+            let left = (view.size.width - line.width) * self.horizontal_align.factor() - origin.x;
+            for fragment in &line.fragments {
+                if fragment.glyph_start >= view.visible_glyphs {
+                    break;
+                }
+                let x = left + fragment.x;
+                let y = top + line.height - fragment.height;
+                match &layout.runs[fragment.run] {
+                    RichRun::Text { font_name, text_size, color } => {
+                        let visible_count = (view.visible_glyphs - fragment.glyph_start).min(fragment.glyph_count);
+                        let text = match fragment.text.char_indices().nth(visible_count) {
+                            Some((end, _)) => &fragment.text[..end],
+                            None => &fragment.text,
+                        };
+                        graphics.draw_text(
+                            provider.font(font_name)?,
+                            text,
+                            TextDrawParams::default()
+                                .text_size(*text_size)
+                                .color(*color),
+                            Transform::from(concat_transform(Transform2D::default().translate((x, y)), transform)),
+                        );
+                    }
+                    RichRun::Image { frame, scale } => {
+                        let local = Transform2D::default()
+                            .scale((*scale, *scale))
+                            .translate((x, y));
+                        frame.draw_with(graphics, provider, image_color, (false, false), Some(concat_transform(local, transform)))?;
+                    }
+                    RichRun::Pause => {}
+                }
+            }
+            top += line.height * layout.line_spacing;
+        }
+        Ok(())
+    }
+
+    fn origin(&self, size: Size) -> Position {
+        Position::new(size.width * self.horizontal_align.factor(), size.height * self.vertical_align.factor())
+    }
+
+    pub(super) fn layout(&self, provider: &impl TextureRefProvider) -> GameResult<RichLayout> {
+        let base_line_height = self.metrics.scaled_line_height(self.text_size);
+        let wrap_width = if self.word_wrap { self.max_width } else { None };
+        let mut builder = RichLayoutBuilder::new(wrap_width, base_line_height);
+        let mut runs = Vec::with_capacity(self.rich_text.spans().len());
+        for (index, span) in self.rich_text.spans().iter().enumerate() {
+            match span {
+                RichSpan::Text { text, style } => {
+                    let font_name = style.font_name.as_deref().unwrap_or(&self.font_name);
+                    let text_size = style.text_size.unwrap_or(self.text_size);
+                    let color = style.color.unwrap_or(self.color);
+                    let height = self.metrics.scaled_line_height(text_size);
+                    for c in text.chars() {
+                        match c {
+                            '\n' => {
+                                builder.end_word();
+                                builder.end_line();
+                            }
+                            ' ' => builder.push_space(self.metrics.char_advance(c, text_size)),
+                            _ => builder.push_char(index, c, self.metrics.char_advance(c, text_size), height),
+                        }
+                    }
+                    runs.push(RichRun::Text { font_name: font_name.to_owned(), text_size, color });
+                }
+                RichSpan::Image { res_name } => {
+                    let frame = Frame::by_texture_ref(provider, res_name.as_str(), Position::zero())?;
+                    let frame_size = frame.size();
+                    let scale = if frame_size.height > 0.0 { base_line_height / frame_size.height } else { 0.0 };
+                    builder.push_image(index, frame_size.width * scale, base_line_height);
+                    runs.push(RichRun::Image { frame, scale });
+                }
+                RichSpan::Pause { duration } => {
+                    builder.push_pause(*duration);
+                    runs.push(RichRun::Pause);
+                }
+            }
+        }
+        let (lines, glyphs, pauses) = builder.finish();
+        let widest = lines.iter().map(|line| line.width).fold(0.0, f32::max);
+        let mut layout = RichLayout {
+            runs,
+            lines,
+            glyphs,
+            pauses,
+            size: Size::new(0.0, 0.0),
+            line_spacing: self.line_spacing,
+        };
+        let height = layout.lines_height(0..layout.lines.len());
+        layout.size = Size::new(self.max_width.unwrap_or(widest).max(widest), height);
+        Ok(layout)
+    }
+}
@@ -0,0 +1,165 @@
+use super::color::{HexColorOrder, parse_hex_color};
+use tge::prelude::*;
+use std::mem;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichStyle {
+    pub font_name: Option<String>,
+    pub text_size: Option<f32>,
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RichSpan {
+    Text {
+        text: String,
+        style: RichStyle,
+    },
+    Image {
+        res_name: String,
+    },
+    Pause {
+        duration: Duration,
+    },
+}
+
+#[derive(Debug, Clone)]
+enum RichTag {
+    Color(Color),
+    Font(String),
+    Size(f32),
+}
+
+impl RichTag {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Color(_) => "color",
+            Self::Font(_) => "font",
+            Self::Size(_) => "size",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichText {
+    spans: Vec<RichSpan>,
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(markup: &str) -> GameResult<Self> {
+        let mut rich_text = Self::new();
+        let mut stack: Vec<(RichTag, usize)> = Vec::new();
+        let mut text = String::new();
+        let mut rest = markup;
+        while let Some(start) = rest.find('[') {
+            text.push_str(&rest[..start]);
+            let offset = markup.len() - rest.len() + start;
+            rest = &rest[start + 1..];
+            if let Some(escaped) = rest.strip_prefix('[') {
+                text.push('[');
+                rest = escaped;
+                continue;
+            }
+            let end = rest.find(']')
+                .ok_or_else(|| GameError::RuntimeError(format!("unterminated tag at {}", offset)))?;
+            let tag = rest[..end].trim();
+            rest = &rest[end + 1..];
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                match stack.last() {
+                    Some((open, _)) if open.name() == name => {}
+                    Some((open, _)) => return Err(GameError::RuntimeError(format!("mismatched closing tag [/{}] at {}, expected [/{}]", name, offset, open.name()))),
+                    None => return Err(GameError::RuntimeError(format!("unexpected closing tag [/{}] at {}", name, offset))),
+                }
+                rich_text.push_text(&mut text, &stack);
+                stack.pop();
+                continue;
+            }
+            let (name, value) = match tag.split_once('=') {
+                Some((name, value)) => (name.trim(), value.trim()),
+                None => (tag, ""),
+            };
+            if value.is_empty() {
+                return Err(GameError::RuntimeError(format!("tag [{}] at {} needs a value", name, offset)));
+            }
+            let open = match name {
+                "color" => RichTag::Color(parse_color(value)?),
+                "font" => RichTag::Font(value.to_owned()),
+                "size" => RichTag::Size(value.parse::<f32>()
+                    .ok()
+                    .filter(|size| *size > 0.0)
+                    .ok_or_else(|| GameError::RuntimeError(format!("invalid size: {}", value)))?),
+                "pause" => {
+                    let duration = value.parse::<f32>()
+                        .ok()
+                        .and_then(|seconds| Duration::try_from_secs_f32(seconds).ok())
+                        .ok_or_else(|| GameError::RuntimeError(format!("invalid pause: {}", value)))?;
+                    rich_text.push_text(&mut text, &stack);
+                    rich_text.spans.push(RichSpan::Pause { duration });
+                    continue;
+                }
+                "img" => {
+                    rich_text.push_text(&mut text, &stack);
+                    rich_text.spans.push(RichSpan::Image { res_name: value.to_owned() });
+                    continue;
+                }
+                _ => return Err(GameError::RuntimeError(format!("unknown tag [{}] at {}", name, offset))),
+            };
+            rich_text.push_text(&mut text, &stack);
+            stack.push((open, offset));
+        }
+        text.push_str(rest);
+        if let Some((open, offset)) = stack.last() {
+            return Err(GameError::RuntimeError(format!("unclosed tag [{}] at {}", open.name(), offset)));
+        }
+        rich_text.push_text(&mut text, &stack);
+        Ok(rich_text)
+    }
+
+    pub fn spans(&self) -> &[RichSpan] {
+        &self.spans
+    }
+
+    pub fn push_span(&mut self, span: RichSpan) {
+        self.spans.push(span);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    pub fn plain_text(&self) -> String {
+        self.spans.iter()
+            .filter_map(|span| match span {
+                RichSpan::Text { text, .. } => Some(text.as_str()),
+                RichSpan::Image { .. } | RichSpan::Pause { .. } => None,
+            })
+            .collect()
+    }
+
+    fn push_text(&mut self, text: &mut String, stack: &[(RichTag, usize)]) {
+        if text.is_empty() {
+            return;
+        }
+        let mut style = RichStyle::default();
+        for (tag, _) in stack {
+            match tag {
+                RichTag::Color(color) => style.color = Some(*color),
+                RichTag::Font(font_name) => style.font_name = Some(font_name.clone()),
+                RichTag::Size(text_size) => style.text_size = Some(*text_size),
+            }
+        }
+        self.spans.push(RichSpan::Text { text: mem::take(text), style });
+    }
+}
+
+fn parse_color(value: &str) -> GameResult<Color> {
+    value.strip_prefix('#')
+        .and_then(|hex| parse_hex_color(hex, HexColorOrder::Rgba))
+        .ok_or_else(|| GameError::RuntimeError(format!("invalid color: {}", value)))
+}
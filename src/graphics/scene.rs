@@ -0,0 +1,328 @@
+use super::{Sprite, Animation, Drawable};
+use super::transform::{Transform2D, concat_transform};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+#[derive(Clone)]
+pub enum NodeContent {
+    Sprite(Sprite),
+    Animation(Animation),
+}
+
+impl From<Sprite> for NodeContent {
+    fn from(sprite: Sprite) -> Self {
+        Self::Sprite(sprite)
+    }
+}
+
+impl From<Animation> for NodeContent {
+    fn from(animation: Animation) -> Self {
+        Self::Animation(animation)
+    }
+}
+
+#[derive(Clone)]
+pub struct SceneNode {
+    position: Position,
+    rotation: Angle,
+    scale: Vector,
+    origin: Position,
+    visible: bool,
+    content: Option<NodeContent>,
+}
+
+impl SceneNode {
+    pub fn new() -> Self {
+        Self {
+            position: Position::zero(),
+            rotation: Angle::zero(),
+            scale: Vector::new(1.0, 1.0),
+            origin: Position::zero(),
+            visible: true,
+            content: None,
+        }
+    }
+
+    pub fn with_content(content: impl Into<NodeContent>) -> Self {
+        let mut node = Self::new();
+        node.set_content(Some(content.into()));
+        node
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: impl Into<Position>) {
+        self.position = position.into();
+    }
+
+    pub fn rotation(&self) -> Angle {
+        self.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: Angle) {
+        self.rotation = rotation;
+    }
+
+    pub fn scale(&self) -> Vector {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: impl Into<Vector>) {
+        self.scale = scale.into();
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn set_origin(&mut self, origin: impl Into<Position>) {
+        self.origin = origin.into();
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    pub fn content(&self) -> Option<&NodeContent> {
+        self.content.as_ref()
+    }
+
+    pub fn content_mut(&mut self) -> Option<&mut NodeContent> {
+        self.content.as_mut()
+    }
+
+    pub fn set_content(&mut self, content: Option<NodeContent>) {
+        self.content = content;
+    }
+
+    pub fn local_transform(&self) -> Transform2D {
+        self.append_local_transform(Transform2D::default())
+    }
+
+    fn append_local_transform(&self, transform: Transform2D) -> Transform2D {
+        transform
+            .translate((-self.origin.x, -self.origin.y))
+            .scale(self.scale)
+            .rotate(self.rotation)
+            .translate(self.position)
+    }
+}
+
+impl Default for SceneNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct NodeEntry {
+    node: SceneNode,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+struct NodeSlot {
+    generation: u32,
+    entry: Option<NodeEntry>,
+}
+
+pub struct SceneGraph {
+    slots: Vec<NodeSlot>,
+    free_indices: Vec<usize>,
+    roots: Vec<NodeId>,
+}
+
+impl SceneGraph {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free_indices: Vec::new(),
+            roots: Vec::new(),
+        }
+    }
+
+    pub fn add_root(&mut self, node: SceneNode) -> NodeId {
+        let id = self.push_entry(node, None);
+        self.roots.push(id);
+        id
+    }
+
+    pub fn add_child(&mut self, parent: NodeId, node: SceneNode) -> GameResult<NodeId> {
+        if self.entry(parent).is_none() {
+            return Err(GameError::RuntimeError("parent node not exists".into()));
+        }
+        let id = self.push_entry(node, Some(parent));
+        if let Some(entry) = self.entry_mut(parent) {
+            entry.children.push(id);
+        }
+        Ok(id)
+    }
+
+    fn push_entry(&mut self, node: SceneNode, parent: Option<NodeId>) -> NodeId {
+        let entry = NodeEntry {
+            node,
+            parent,
+            children: Vec::new(),
+        };
+        match self.free_indices.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.entry = Some(entry);
+                NodeId {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                self.slots.push(NodeSlot {
+                    generation: 0,
+                    entry: Some(entry),
+                });
+                NodeId {
+                    index: self.slots.len() - 1,
+                    generation: 0,
+                }
+            }
+        }
+    }
+
+    fn take_entry(&mut self, id: NodeId) -> Option<NodeEntry> {
+        let slot = self.slots.get_mut(id.index).filter(|slot| slot.generation == id.generation)?;
+        let entry = slot.entry.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_indices.push(id.index);
+        Some(entry)
+    }
+
+    pub fn remove(&mut self, id: NodeId) -> Option<SceneNode> {
+        let entry = self.take_entry(id)?;
+        match entry.parent {
+            Some(parent) => {
+                if let Some(parent) = self.entry_mut(parent) {
+                    parent.children.retain(|child| *child != id);
+                }
+            }
+            None => self.roots.retain(|root| *root != id),
+        }
+        let mut pending = entry.children;
+        while let Some(child) = pending.pop() {
+            if let Some(child) = self.take_entry(child) {
+                pending.extend(child.children);
+            }
+        }
+        Some(entry.node)
+    }
+
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.entry(id).is_some()
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&SceneNode> {
+        self.entry(id).map(|entry| &entry.node)
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> Option<&mut SceneNode> {
+        self.entry_mut(id).map(|entry| &mut entry.node)
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.entry(id).and_then(|entry| entry.parent)
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        self.entry(id).map(|entry| entry.children.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    fn entry(&self, id: NodeId) -> Option<&NodeEntry> {
+        self.slots.get(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entry.as_ref())
+    }
+
+    fn entry_mut(&mut self, id: NodeId) -> Option<&mut NodeEntry> {
+        self.slots.get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entry.as_mut())
+    }
+
+    pub fn world_transform(&self, id: NodeId) -> Option<Transform2D> {
+        let mut transform = Transform2D::default();
+        let mut current = Some(id);
+        while let Some(id) = current {
+            let entry = self.entry(id)?;
+            transform = entry.node.append_local_transform(transform);
+            current = entry.parent;
+        }
+        Some(transform)
+    }
+
+    pub fn local_to_world(&self, id: NodeId, position: impl Into<Position>) -> Option<Position> {
+        self.world_transform(id).map(|transform| transform.transform_position(position))
+    }
+
+    pub fn world_to_local(&self, id: NodeId, position: impl Into<Position>) -> Option<Position> {
+        self.world_transform(id)
+            .and_then(|transform| transform.inverse())
+            .map(|transform| transform.transform_position(position))
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        for entry in self.slots.iter_mut().filter_map(|slot| slot.entry.as_mut()) {
+            if let Some(NodeContent::Animation(animation)) = &mut entry.node.content {
+                animation.update(delta_time);
+            }
+        }
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let transform = transform.into();
+        for root in &self.roots {
+            self.draw_node(graphics, provider, *root, transform)?;
+        }
+        Ok(())
+    }
+
+    fn draw_node(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, id: NodeId, transform: Option<Transform2D>) -> GameResult {
+        let entry = match self.entry(id) {
+            Some(entry) => entry,
+            None => return Ok(()),
+        };
+        if !entry.node.visible {
+            return Ok(());
+        }
+        let transform = Some(concat_transform(entry.node.local_transform(), transform));
+        if let Some(content) = &entry.node.content {
+            let drawable = match content {
+                NodeContent::Sprite(sprite) => Drawable::Sprite(sprite),
+                NodeContent::Animation(animation) => Drawable::Animation(animation),
+            };
+            drawable.draw(graphics, provider, transform)?;
+        }
+        for child in &entry.children {
+            self.draw_node(graphics, provider, *child, transform)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for SceneGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
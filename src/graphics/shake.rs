@@ -0,0 +1,155 @@
+use super::{Camera2D, ResolutionAdapter};
+use super::random::value_noise;
+use super::transform::{Transform2D, concat_transform};
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraShake {
+    seed: u64,
+    trauma: f32,
+    trauma_decay: f32,
+    trauma_power: f32,
+    frequency: f32,
+    max_offset: Vector,
+    max_rotation: Angle,
+    time: f32,
+}
+
+impl CameraShake {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            trauma: 0.0,
+            trauma_decay: 1.0,
+            trauma_power: 2.0,
+            frequency: 15.0,
+            max_offset: Vector::new(16.0, 16.0),
+            max_rotation: Angle::degrees(5.0),
+            time: 0.0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.trauma = 0.0;
+        self.time = 0.0;
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    pub fn set_trauma(&mut self, trauma: f32) {
+        self.trauma = trauma.clamp(0.0, 1.0);
+    }
+
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.set_trauma(self.trauma + trauma);
+    }
+
+    pub fn trauma_decay(&self) -> f32 {
+        self.trauma_decay
+    }
+
+    pub fn set_trauma_decay(&mut self, trauma_decay: f32) {
+        self.trauma_decay = trauma_decay.max(0.0);
+    }
+
+    pub fn trauma_power(&self) -> f32 {
+        self.trauma_power
+    }
+
+    pub fn set_trauma_power(&mut self, trauma_power: f32) {
+        self.trauma_power = trauma_power;
+    }
+
+    pub fn frequency(&self) -> f32 {
+        self.frequency
+    }
+
+    pub fn set_frequency(&mut self, frequency: f32) {
+        self.frequency = frequency;
+    }
+
+    pub fn max_offset(&self) -> Vector {
+        self.max_offset
+    }
+
+    pub fn set_max_offset(&mut self, max_offset: impl Into<Vector>) {
+        self.max_offset = max_offset.into();
+    }
+
+    pub fn max_rotation(&self) -> Angle {
+        self.max_rotation
+    }
+
+    pub fn set_max_rotation(&mut self, max_rotation: Angle) {
+        self.max_rotation = max_rotation;
+    }
+
+    pub fn is_shaking(&self) -> bool {
+        self.trauma > 0.0
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        let delta_time_f32 = delta_time.as_secs_f32();
+        self.time += delta_time_f32;
+        self.trauma = (self.trauma - self.trauma_decay * delta_time_f32).max(0.0);
+    }
+
+    fn intensity(&self) -> f32 {
+        self.trauma.powf(self.trauma_power)
+    }
+
+    pub fn offset(&self) -> Vector {
+        let intensity = self.intensity();
+        let time = self.time * self.frequency;
+        Vector::new(
+            self.max_offset.x * intensity * value_noise(self.seed, 0, time),
+            self.max_offset.y * intensity * value_noise(self.seed, 1, time),
+        )
+    }
+
+    pub fn rotation(&self) -> Angle {
+        let intensity = self.intensity();
+        let time = self.time * self.frequency;
+        Angle::radians(self.max_rotation.radians_value() * intensity * value_noise(self.seed, 2, time))
+    }
+
+    pub fn transform(&self, pivot: impl Into<Position>) -> Transform2D {
+        let pivot = pivot.into();
+        let offset = self.offset();
+        Transform2D::default()
+            .translate((-pivot.x, -pivot.y))
+            .rotate(self.rotation())
+            .translate((pivot.x + offset.x, pivot.y + offset.y))
+    }
+
+    pub fn apply(&self, transform: impl Into<Option<Transform2D>>, pivot: impl Into<Position>) -> Transform2D {
+        concat_transform(transform.into().unwrap_or_default(), Some(self.transform(pivot)))
+    }
+
+    pub fn camera_transform(&self, camera: &Camera2D) -> Transform2D {
+        let viewport_size = camera.viewport_size();
+        self.apply(camera.transform(), (viewport_size.width / 2.0, viewport_size.height / 2.0))
+    }
+
+    pub fn canvas_transform(&self, adapter: &impl ResolutionAdapter) -> Transform2D {
+        let canvas_size = adapter.canvas_size();
+        self.transform((canvas_size.width / 2.0, canvas_size.height / 2.0))
+    }
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
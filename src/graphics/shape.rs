@@ -0,0 +1,316 @@
+use super::transform::{Transform2D, concat_transform, transform_bounds};
+use tge::prelude::*;
+use std::cmp::Ordering;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+const SPAN_MERGE_EPSILON: f32 = 0.001;
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum ShapeStyle {
+    #[default]
+    Fill,
+    Stroke(f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ShapeKind {
+    Rect(Region),
+    RoundedRect(Region, f32),
+    Line(Position, Position),
+    Circle(Position, f32),
+    Arc(Position, f32, Angle, Angle),
+    Polygon(Vec<Position>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    kind: ShapeKind,
+    style: ShapeStyle,
+    color: Color,
+    segments: Option<usize>,
+    scanline_height: f32,
+}
+
+impl Shape {
+    pub fn rect(region: impl Into<Region>) -> Self {
+        Self::new(ShapeKind::Rect(region.into()), ShapeStyle::Fill)
+    }
+
+    pub fn rounded_rect(region: impl Into<Region>, radius: f32) -> Self {
+        Self::new(ShapeKind::RoundedRect(region.into(), radius.max(0.0)), ShapeStyle::Fill)
+    }
+
+    pub fn line(from: impl Into<Position>, to: impl Into<Position>, thickness: f32) -> Self {
+        Self::new(ShapeKind::Line(from.into(), to.into()), ShapeStyle::Stroke(thickness))
+    }
+
+    pub fn circle(center: impl Into<Position>, radius: f32) -> Self {
+        Self::new(ShapeKind::Circle(center.into(), radius.max(0.0)), ShapeStyle::Fill)
+    }
+
+    pub fn arc(center: impl Into<Position>, radius: f32, start: Angle, end: Angle) -> Self {
+        Self::new(ShapeKind::Arc(center.into(), radius.max(0.0), start, end), ShapeStyle::Stroke(1.0))
+    }
+
+    pub fn polygon(points: Vec<Position>) -> Self {
+        Self::new(ShapeKind::Polygon(points), ShapeStyle::Fill)
+    }
+
+    fn new(kind: ShapeKind, style: ShapeStyle) -> Self {
+        Self {
+            kind,
+            style,
+            color: Color::WHITE,
+            segments: None,
+            scanline_height: 1.0,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    pub fn style(mut self, style: ShapeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn fill(self) -> Self {
+        self.style(ShapeStyle::Fill)
+    }
+
+    pub fn stroke(self, thickness: f32) -> Self {
+        self.style(ShapeStyle::Stroke(thickness))
+    }
+
+    pub fn segments(mut self, segments: impl Into<Option<usize>>) -> Self {
+        self.segments = segments.into();
+        self
+    }
+
+    pub fn scanline_height(mut self, scanline_height: f32) -> Self {
+        assert!(scanline_height > 0.0, "scanline height must be positive");
+        self.scanline_height = scanline_height;
+        self
+    }
+
+    pub fn points(&self) -> Vec<Position> {
+        match &self.kind {
+            ShapeKind::Rect(region) => rect_points(*region),
+            ShapeKind::RoundedRect(region, radius) => {
+                let radius = radius.min(region.width / 2.0).min(region.height / 2.0);
+                if radius <= 0.0 {
+                    return rect_points(*region);
+                }
+                let segments = self.arc_segments(radius, FRAC_PI_2);
+                let corners = [
+                    (region.x + region.width - radius, region.y + region.height - radius, 0.0),
+                    (region.x + radius, region.y + region.height - radius, FRAC_PI_2),
+                    (region.x + radius, region.y + radius, PI),
+                    (region.x + region.width - radius, region.y + radius, PI + FRAC_PI_2),
+                ];
+                corners.iter()
+                    .flat_map(|(x, y, start)| arc_points(Position::new(*x, *y), radius, *start, FRAC_PI_2, segments))
+                    .collect()
+            }
+            ShapeKind::Line(from, to) => vec![*from, *to],
+            ShapeKind::Circle(center, radius) => {
+                let segments = self.arc_segments(*radius, TAU);
+                let mut points = arc_points(*center, *radius, 0.0, TAU, segments);
+                points.pop();
+                points
+            }
+            ShapeKind::Arc(center, radius, start, end) => {
+                let start = start.radians_value();
+                let sweep = end.radians_value() - start;
+                let mut points = arc_points(*center, *radius, start, sweep, self.arc_segments(*radius, sweep.abs()));
+                if self.style == ShapeStyle::Fill {
+                    points.push(*center);
+                }
+                points
+            }
+            ShapeKind::Polygon(points) => points.clone(),
+        }
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        let points = self.points();
+        if points.is_empty() {
+            return transform_bounds(transform.into(), Region::new(0.0, 0.0, 0.0, 0.0));
+        }
+        let expand = match self.style {
+            ShapeStyle::Fill => 0.0,
+            ShapeStyle::Stroke(thickness) => thickness / 2.0,
+        };
+        let min_x = points.iter().map(|point| point.x).fold(f32::INFINITY, f32::min) - expand;
+        let min_y = points.iter().map(|point| point.y).fold(f32::INFINITY, f32::min) - expand;
+        let max_x = points.iter().map(|point| point.x).fold(f32::NEG_INFINITY, f32::max) + expand;
+        let max_y = points.iter().map(|point| point.y).fold(f32::NEG_INFINITY, f32::max) + expand;
+        transform_bounds(transform.into(), Region::new(min_x, min_y, max_x - min_x, max_y - min_y))
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, transform: impl Into<Option<Transform2D>>) {
+        let transform = transform.into();
+        match (&self.kind, self.style) {
+            (ShapeKind::Rect(region), ShapeStyle::Fill) => draw_quad(graphics, *region, self.color, transform),
+            (ShapeKind::Rect(region), ShapeStyle::Stroke(thickness)) => {
+                let half = thickness / 2.0;
+                let inner_height = (region.height - thickness).max(0.0);
+                for quad in [
+                    Region::new(region.x - half, region.y - half, region.width + thickness, thickness),
+                    Region::new(region.x - half, region.y + region.height - half, region.width + thickness, thickness),
+                    Region::new(region.x - half, region.y + half, thickness, inner_height),
+                    Region::new(region.x + region.width - half, region.y + half, thickness, inner_height),
+                ] {
+                    draw_quad(graphics, quad, self.color, transform);
+                }
+            }
+            (ShapeKind::Line(from, to), ShapeStyle::Fill) => stroke_polyline(graphics, &[*from, *to], false, 1.0, self.color, transform),
+            (ShapeKind::Line(from, to), ShapeStyle::Stroke(thickness)) => stroke_polyline(graphics, &[*from, *to], false, thickness, self.color, transform),
+            (ShapeKind::Arc(..), ShapeStyle::Stroke(thickness)) => stroke_polyline(graphics, &self.points(), false, thickness, self.color, transform),
+            (_, ShapeStyle::Fill) => fill_polygon(graphics, &self.points(), self.scanline_height, self.color, transform),
+            (_, ShapeStyle::Stroke(thickness)) => stroke_polyline(graphics, &self.points(), true, thickness, self.color, transform),
+        }
+    }
+
+    fn arc_segments(&self, radius: f32, sweep: f32) -> usize {
+        let full = self.segments.unwrap_or_else(|| (radius.sqrt() * 6.0).ceil().clamp(12.0, 128.0) as usize);
+        ((full as f32 * sweep / TAU).ceil() as usize).max(1)
+    }
+}
+
+fn rect_points(region: Region) -> Vec<Position> {
+    vec![
+        Position::new(region.x, region.y),
+        Position::new(region.x + region.width, region.y),
+        Position::new(region.x + region.width, region.y + region.height),
+        Position::new(region.x, region.y + region.height),
+    ]
+}
+
+fn arc_points(center: Position, radius: f32, start: f32, sweep: f32, segments: usize) -> Vec<Position> {
+    (0..=segments)
+        .map(|index| {
+            let angle = start + sweep * index as f32 / segments as f32;
+            Position::new(center.x + radius * angle.cos(), center.y + radius * angle.sin())
+        })
+        .collect()
+}
+
+fn draw_quad(graphics: &mut Graphics, region: Region, color: Color, transform: Option<Transform2D>) {
+    if region.width <= 0.0 || region.height <= 0.0 {
+        return;
+    }
+    graphics.draw_sprite(
+        TextureRef::None,
+        SpriteDrawParams::default()
+            .region((0.0, 0.0, region.width, region.height))
+            .color(color),
+        Transform::from(concat_transform(Transform2D::default().translate((region.x, region.y)), transform)),
+    );
+}
+
+fn stroke_polyline(graphics: &mut Graphics, points: &[Position], closed: bool, thickness: f32, color: Color, transform: Option<Transform2D>) {
+    if thickness <= 0.0 {
+        return;
+    }
+    let mut points = points.to_vec();
+    points.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    if closed && points.len() > 1 && points[0].x == points[points.len() - 1].x && points[0].y == points[points.len() - 1].y {
+        points.pop();
+    }
+    let segments_len = match points.len() {
+        0 | 1 => return,
+        2 => 1,
+        len if closed => len,
+        len => len - 1,
+    };
+    let half = thickness / 2.0;
+    let direction = |index: usize| {
+        let from = points[index % points.len()];
+        let to = points[(index + 1) % points.len()];
+        let length = ((to.x - from.x).powi(2) + (to.y - from.y).powi(2)).sqrt();
+        Vector::new((to.x - from.x) / length, (to.y - from.y) / length)
+    };
+    for index in 0..segments_len {
+        let start_extend = if (closed && segments_len > 1) || index > 0 {
+            join_extend(direction((index + segments_len - 1) % segments_len), direction(index), half)
+        } else {
+            half
+        };
+        let end_extend = if (closed && segments_len > 1) || index + 1 < segments_len {
+            join_extend(direction(index), direction(index + 1), half)
+        } else {
+            half
+        };
+        draw_segment(graphics, points[index], points[(index + 1) % points.len()], thickness, (start_extend, end_extend), color, transform);
+    }
+}
+
+fn join_extend(incoming: Vector, outgoing: Vector, half: f32) -> f32 {
+    let cos = (incoming.x * outgoing.x + incoming.y * outgoing.y).clamp(-1.0, 1.0);
+    if cos <= 0.0 {
+        return half;
+    }
+    half * ((1.0 - cos) / (1.0 + cos)).sqrt()
+}
+
+fn draw_segment(graphics: &mut Graphics, from: Position, to: Position, thickness: f32, (start_extend, end_extend): (f32, f32), color: Color, transform: Option<Transform2D>) {
+    let dx = to.x - from.x;
+    let dy = to.y - from.y;
+    let length = (dx * dx + dy * dy).sqrt();
+    graphics.draw_sprite(
+        TextureRef::None,
+        SpriteDrawParams::default()
+            .region((0.0, 0.0, start_extend + length + end_extend, thickness))
+            .origin((start_extend, thickness / 2.0))
+            .color(color),
+        Transform::from(concat_transform(
+            Transform2D::default()
+                .rotate(Angle::radians(dy.atan2(dx)))
+                .translate((from.x, from.y)),
+            transform,
+        )),
+    );
+}
+
+fn fill_polygon(graphics: &mut Graphics, points: &[Position], scanline_height: f32, color: Color, transform: Option<Transform2D>) {
+    if points.len() < 3 || points.iter().any(|point| !point.x.is_finite() || !point.y.is_finite()) {
+        return;
+    }
+    let min_y = points.iter().map(|point| point.y).fold(f32::INFINITY, f32::min);
+    let max_y = points.iter().map(|point| point.y).fold(f32::NEG_INFINITY, f32::max);
+    let rows = ((max_y - min_y) / scanline_height).ceil() as usize;
+    let mut crossings = Vec::new();
+    let mut spans: Vec<Region> = Vec::new();
+    for row in 0..rows {
+        let top = min_y + row as f32 * scanline_height;
+        let height = scanline_height.min(max_y - top);
+        let y = top + height / 2.0;
+        crossings.clear();
+        for (index, start) in points.iter().enumerate() {
+            let end = points[(index + 1) % points.len()];
+            if (start.y <= y && y < end.y) || (end.y <= y && y < start.y) {
+                crossings.push(start.x + (y - start.y) * (end.x - start.x) / (end.y - start.y));
+            }
+        }
+        crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let mergeable = crossings.len() / 2 == spans.len() && crossings.chunks_exact(2).zip(&spans).all(|(pair, span)| {
+            (pair[0] - span.x).abs() <= SPAN_MERGE_EPSILON && (pair[1] - span.x - span.width).abs() <= SPAN_MERGE_EPSILON
+        });
+        if mergeable {
+            for span in &mut spans {
+                span.height += height;
+            }
+            continue;
+        }
+        for span in spans.drain(..) {
+            draw_quad(graphics, span, color, transform);
+        }
+        spans.extend(crossings.chunks_exact(2).map(|pair| Region::new(pair[0], top, pair[1] - pair[0], height)));
+    }
+    for span in spans {
+        draw_quad(graphics, span, color, transform);
+    }
+}
@@ -0,0 +1,171 @@
+use super::{Frame, Origin, Animation, get_texture_region};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SheetCell {
+    Size(Size),
+    Count(usize, usize),
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum SheetOrder {
+    #[default]
+    RowMajor,
+    ColumnMajor,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SheetGrid {
+    cell: SheetCell,
+    margin: f32,
+    spacing: f32,
+    max_frames: Option<usize>,
+    order: SheetOrder,
+    origin: Origin,
+}
+
+impl SheetGrid {
+    pub fn by_cell_size(cell_size: impl Into<Size>) -> Self {
+        let cell_size = cell_size.into();
+        assert!(cell_size.width > 0.0 && cell_size.height > 0.0, "cell size must be positive");
+        Self::new(SheetCell::Size(cell_size))
+    }
+
+    pub fn by_cell_count(cols: usize, rows: usize) -> Self {
+        assert!(cols > 0 && rows > 0, "cell count must be non-zero");
+        Self::new(SheetCell::Count(cols, rows))
+    }
+
+    fn new(cell: SheetCell) -> Self {
+        Self {
+            cell,
+            margin: 0.0,
+            spacing: 0.0,
+            max_frames: None,
+            order: SheetOrder::default(),
+            origin: Origin::default(),
+        }
+    }
+
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        assert!(spacing >= 0.0, "spacing must not be negative");
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn max_frames(mut self, max_frames: impl Into<Option<usize>>) -> Self {
+        self.max_frames = max_frames.into();
+        self
+    }
+
+    pub fn order(mut self, order: SheetOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn origin(mut self, origin: impl Into<Origin>) -> Self {
+        self.origin = origin.into();
+        self
+    }
+
+    pub fn measure(&self, region: impl Into<Region>) -> (Size, usize, usize) {
+        let region = region.into();
+        let inner_width = region.width - self.margin * 2.0;
+        let inner_height = region.height - self.margin * 2.0;
+        match self.cell {
+            SheetCell::Size(cell_size) => {
+                let cols = ((inner_width + self.spacing) / (cell_size.width + self.spacing)).floor().max(0.0) as usize;
+                let rows = ((inner_height + self.spacing) / (cell_size.height + self.spacing)).floor().max(0.0) as usize;
+                (cell_size, cols, rows)
+            }
+            SheetCell::Count(cols, rows) => {
+                let cell_size = Size::new(
+                    (inner_width - self.spacing * cols.saturating_sub(1) as f32) / cols as f32,
+                    (inner_height - self.spacing * rows.saturating_sub(1) as f32) / rows as f32,
+                );
+                (cell_size, cols, rows)
+            }
+        }
+    }
+
+    pub fn cell_region(&self, region: impl Into<Region>, col: usize, row: usize) -> Region {
+        let region = region.into();
+        let (cell_size, _, _) = self.measure(region);
+        Region::new(
+            region.x + self.margin + col as f32 * (cell_size.width + self.spacing),
+            region.y + self.margin + row as f32 * (cell_size.height + self.spacing),
+            cell_size.width,
+            cell_size.height,
+        )
+    }
+
+    pub fn cell_regions(&self, region: impl Into<Region>) -> Vec<Region> {
+        let region = region.into();
+        let (_, cols, rows) = self.measure(region);
+        let cells: Vec<(usize, usize)> = match self.order {
+            SheetOrder::RowMajor => (0..rows).flat_map(|row| (0..cols).map(move |col| (col, row))).collect(),
+            SheetOrder::ColumnMajor => (0..cols).flat_map(|col| (0..rows).map(move |row| (col, row))).collect(),
+        };
+        cells.into_iter()
+            .take(self.max_frames.unwrap_or(usize::MAX))
+            .map(|(col, row)| self.cell_region(region, col, row))
+            .collect()
+    }
+
+    pub fn split(&self, res_name: impl AsRef<str>, region: impl Into<Region>) -> Vec<Frame> {
+        let res_name = res_name.as_ref();
+        self.cell_regions(region)
+            .into_iter()
+            .map(|region| self.frame(res_name, region))
+            .collect()
+    }
+
+    pub fn split_by_texture_ref(&self, provider: &impl TextureRefProvider, res_name: impl AsRef<str>) -> GameResult<Vec<Frame>> {
+        let region = get_texture_region(provider, &res_name)?;
+        Ok(self.split(res_name, region))
+    }
+
+    pub fn split_row(&self, res_name: impl AsRef<str>, region: impl Into<Region>, row: usize, frames_len: usize) -> Vec<Frame> {
+        let res_name = res_name.as_ref();
+        let region = region.into();
+        let (_, cols, rows) = self.measure(region);
+        let (lines_len, line_len) = match self.order {
+            SheetOrder::RowMajor => (rows, cols),
+            SheetOrder::ColumnMajor => (cols, rows),
+        };
+        if row >= lines_len {
+            return Vec::new();
+        }
+        let max_frames = self.max_frames.unwrap_or(usize::MAX).saturating_sub(row * line_len);
+        (0..frames_len.min(line_len).min(max_frames))
+            .map(|index| match self.order {
+                SheetOrder::RowMajor => (index, row),
+                SheetOrder::ColumnMajor => (row, index),
+            })
+            .map(|(col, row)| self.frame(res_name, self.cell_region(region, col, row)))
+            .collect()
+    }
+
+    pub fn split_animations(&self, res_name: impl AsRef<str>, region: impl Into<Region>, frame_duration: Duration, rows: &[(&str, usize)]) -> HashMap<String, Animation> {
+        let res_name = res_name.as_ref();
+        let region = region.into();
+        rows.iter()
+            .enumerate()
+            .map(|(row, (name, frames_len))| (name, self.split_row(res_name, region, row, *frames_len)))
+            .filter(|(_, frames)| !frames.is_empty())
+            .map(|(name, frames)| (name.to_string(), Animation::new(frame_duration, frames)))
+            .collect()
+    }
+
+    fn frame(&self, res_name: &str, region: Region) -> Frame {
+        Frame::new(res_name, region, self.origin)
+    }
+}
@@ -1,21 +1,17 @@
-use super::{Frame, get_texture_region};
+use super::{Anchor, Origin, Frame, Transform2D, get_texture_region};
 use crate::asset::TextureRefProvider;
 use tge::prelude::*;
 
 #[derive(Clone)]
 pub struct Sprite {
-    res_name: String,
-    region: Region,
-    origin: Position,
+    frame: Frame,
     color: Color,
 }
 
 impl Sprite {
     pub fn new(res_name: impl Into<String>, region: impl Into<Region>) -> Self {
         Self {
-            res_name: res_name.into(),
-            region: region.into(),
-            origin: Position::zero(),
+            frame: Frame::new(res_name, region, Position::zero()),
             color: Color::WHITE,
         }
     }
@@ -26,28 +22,80 @@ impl Sprite {
         Ok(Self::new(res_name, region))
     }
 
+    pub fn frame(&self) -> &Frame {
+        &self.frame
+    }
+
     pub fn res_name(&self) -> &str {
-        &self.res_name
+        self.frame.res_name()
     }
 
     pub fn set_res_name(&mut self, res_name: impl Into<String>) {
-        self.res_name = res_name.into();
+        self.frame.set_res_name(res_name);
     }
 
     pub fn region(&self) -> Region {
-        self.region
+        self.frame.region()
     }
 
     pub fn set_region(&mut self, region: impl Into<Region>) {
-        self.region = region.into();
+        self.frame.set_region(region);
     }
 
     pub fn origin(&self) -> Position {
-        self.origin
+        self.frame.origin()
+    }
+
+    pub fn set_origin(&mut self, origin: impl Into<Origin>) {
+        self.frame.set_origin(origin);
+    }
+
+    pub fn anchor(&self) -> Option<Anchor> {
+        self.frame.anchor()
+    }
+
+    pub fn set_anchor(&mut self, anchor: impl Into<Anchor>) {
+        self.frame.set_anchor(anchor);
+    }
+
+    pub fn source_size(&self) -> Option<Size> {
+        self.frame.source_size()
+    }
+
+    pub fn set_source_size(&mut self, source_size: impl Into<Option<Size>>) {
+        self.frame.set_source_size(source_size);
+    }
+
+    pub fn trim_offset(&self) -> Position {
+        self.frame.trim_offset()
     }
 
-    pub fn set_origin(&mut self, origin: impl Into<Position>) {
-        self.origin = origin.into();
+    pub fn set_trim_offset(&mut self, trim_offset: impl Into<Position>) {
+        self.frame.set_trim_offset(trim_offset);
+    }
+
+    pub fn is_rotated(&self) -> bool {
+        self.frame.is_rotated()
+    }
+
+    pub fn set_rotated(&mut self, rotated: bool) {
+        self.frame.set_rotated(rotated);
+    }
+
+    pub fn flip_x(&self) -> bool {
+        self.frame.flip_x()
+    }
+
+    pub fn set_flip_x(&mut self, flip_x: bool) {
+        self.frame.set_flip_x(flip_x);
+    }
+
+    pub fn flip_y(&self) -> bool {
+        self.frame.flip_y()
+    }
+
+    pub fn set_flip_y(&mut self, flip_y: bool) {
+        self.frame.set_flip_y(flip_y);
     }
 
     pub fn color(&self) -> Color {
@@ -58,25 +106,19 @@ impl Sprite {
         self.color = color.into();
     }
 
-    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform>>) -> GameResult {
-        graphics.draw_sprite(
-            provider.texture_ref(&self.res_name)?,
-            SpriteDrawParams::default()
-                .region(self.region)
-                .origin(self.origin)
-                .color(self.color),
-            transform,
-        );
-        Ok(())
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        self.frame.bounds(transform)
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        self.frame.draw_with(graphics, provider, self.color, (false, false), transform.into())
     }
 }
 
 impl From<Frame> for Sprite {
     fn from(frame: Frame) -> Self {
         Self {
-            res_name: frame.res_name,
-            region: frame.region,
-            origin: frame.origin,
+            frame,
             color: Color::WHITE,
         }
     }
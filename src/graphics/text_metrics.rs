@@ -0,0 +1,47 @@
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TextMetrics {
+    char_width: f32,
+    space_width: f32,
+    line_height: f32,
+}
+
+impl TextMetrics {
+    pub fn new(char_width: f32, space_width: f32, line_height: f32) -> Self {
+        assert!(char_width >= 0.0 && space_width >= 0.0 && line_height >= 0.0, "text metrics must not be negative");
+        Self {
+            char_width,
+            space_width,
+            line_height,
+        }
+    }
+
+    pub fn char_width(&self) -> f32 {
+        self.char_width
+    }
+
+    pub fn space_width(&self) -> f32 {
+        self.space_width
+    }
+
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    pub fn char_advance(&self, c: char, text_size: f32) -> f32 {
+        if c == ' ' {
+            self.space_width * text_size
+        } else {
+            self.char_width * text_size
+        }
+    }
+
+    pub fn scaled_line_height(&self, text_size: f32) -> f32 {
+        self.line_height * text_size
+    }
+}
+
+impl Default for TextMetrics {
+    fn default() -> Self {
+        Self::new(0.5, 0.25, 1.25)
+    }
+}
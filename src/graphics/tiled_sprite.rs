@@ -0,0 +1,227 @@
+use super::{Origin, Sprite};
+use super::nine_patch::Segment;
+use super::transform::{Transform2D, concat_transform, transform_bounds};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum TileWrap {
+    #[default]
+    Repeat,
+    Clamp,
+}
+
+#[derive(Clone)]
+pub struct TiledSprite {
+    res_name: String,
+    region: Region,
+    size: Size,
+    scroll: Vector,
+    wrap_x: TileWrap,
+    wrap_y: TileWrap,
+    origin: Origin,
+    flip_x: bool,
+    flip_y: bool,
+    color: Color,
+}
+
+impl TiledSprite {
+    pub fn new(res_name: impl Into<String>, region: impl Into<Region>, size: impl Into<Size>) -> Self {
+        Self {
+            res_name: res_name.into(),
+            region: region.into(),
+            size: size.into(),
+            scroll: Vector::zero(),
+            wrap_x: TileWrap::default(),
+            wrap_y: TileWrap::default(),
+            origin: Origin::default(),
+            flip_x: false,
+            flip_y: false,
+            color: Color::WHITE,
+        }
+    }
+
+    pub fn by_sprite(sprite: &Sprite, size: impl Into<Size>) -> Self {
+        assert!(!sprite.is_rotated() && !sprite.frame().is_trimmed(), "rotated or trimmed frame can not be tiled");
+        let mut tiled_sprite = Self::new(sprite.res_name(), sprite.region(), size);
+        tiled_sprite.set_flip_x(sprite.flip_x());
+        tiled_sprite.set_flip_y(sprite.flip_y());
+        tiled_sprite.set_color(sprite.color());
+        tiled_sprite
+    }
+
+    pub fn res_name(&self) -> &str {
+        &self.res_name
+    }
+
+    pub fn set_res_name(&mut self, res_name: impl Into<String>) {
+        self.res_name = res_name.into();
+    }
+
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    pub fn set_region(&mut self, region: impl Into<Region>) {
+        self.region = region.into();
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: impl Into<Size>) {
+        self.size = size.into();
+    }
+
+    pub fn scroll(&self) -> Vector {
+        self.scroll
+    }
+
+    pub fn set_scroll(&mut self, scroll: impl Into<Vector>) {
+        self.scroll = scroll.into();
+    }
+
+    pub fn scroll_by(&mut self, delta: impl Into<Vector>) {
+        let delta = delta.into();
+        self.scroll.x += delta.x;
+        self.scroll.y += delta.y;
+        if self.wrap_x == TileWrap::Repeat && self.region.width > 0.0 {
+            self.scroll.x = self.scroll.x.rem_euclid(self.region.width);
+        }
+        if self.wrap_y == TileWrap::Repeat && self.region.height > 0.0 {
+            self.scroll.y = self.scroll.y.rem_euclid(self.region.height);
+        }
+    }
+
+    pub fn wrap_x(&self) -> TileWrap {
+        self.wrap_x
+    }
+
+    pub fn set_wrap_x(&mut self, wrap_x: TileWrap) {
+        self.wrap_x = wrap_x;
+    }
+
+    pub fn wrap_y(&self) -> TileWrap {
+        self.wrap_y
+    }
+
+    pub fn set_wrap_y(&mut self, wrap_y: TileWrap) {
+        self.wrap_y = wrap_y;
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin.resolve(self.size)
+    }
+
+    pub fn set_origin(&mut self, origin: impl Into<Origin>) {
+        self.origin = origin.into();
+    }
+
+    pub fn flip_x(&self) -> bool {
+        self.flip_x
+    }
+
+    pub fn set_flip_x(&mut self, flip_x: bool) {
+        self.flip_x = flip_x;
+    }
+
+    pub fn flip_y(&self) -> bool {
+        self.flip_y
+    }
+
+    pub fn set_flip_y(&mut self, flip_y: bool) {
+        self.flip_y = flip_y;
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        self.color = color.into();
+    }
+
+    pub fn bounds(&self, transform: impl Into<Option<Transform2D>>) -> Region {
+        let origin = self.origin();
+        transform_bounds(transform.into(), Region::new(-origin.x, -origin.y, self.size.width, self.size.height))
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let transform = transform.into();
+        let origin = self.origin();
+        let segments_x = tile_segments(self.region.x, self.region.width, self.size.width, self.scroll.x, self.wrap_x);
+        let segments_y = tile_segments(self.region.y, self.region.height, self.size.height, self.scroll.y, self.wrap_y);
+        for segment_y in &segments_y {
+            let (source_y, target_y, scale_y) = flip_segment(segment_y, self.region.y, self.region.height, self.flip_y);
+            for segment_x in &segments_x {
+                let (source_x, target_x, scale_x) = flip_segment(segment_x, self.region.x, self.region.width, self.flip_x);
+                graphics.draw_sprite(
+                    provider.texture_ref(&self.res_name)?,
+                    SpriteDrawParams::default()
+                        .region((source_x, source_y, segment_x.source_len, segment_y.source_len))
+                        .color(self.color),
+                    Transform::from(concat_transform(
+                        Transform2D::default()
+                            .scale((scale_x, scale_y))
+                            .translate((target_x - origin.x, target_y - origin.y)),
+                        transform,
+                    )),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn flip_segment(segment: &Segment, region_start: f32, region_len: f32, flip: bool) -> (f32, f32, f32) {
+    if flip {
+        let source_start = region_start * 2.0 + region_len - segment.source_start - segment.source_len;
+        (source_start, segment.target_start + segment.target_len, -1.0)
+    } else {
+        (segment.source_start, segment.target_start, 1.0)
+    }
+}
+
+fn tile_segments(source_start: f32, source_len: f32, target_len: f32, scroll: f32, wrap: TileWrap) -> Vec<Segment> {
+    if source_len <= 0.0 || target_len <= 0.0 {
+        return Vec::new();
+    }
+    match wrap {
+        TileWrap::Repeat => {
+            let source_offset = (-scroll).rem_euclid(source_len);
+            let tiles_len = ((target_len + source_offset) / source_len).ceil() as usize;
+            (0..tiles_len)
+                .filter_map(|index| {
+                    let tile_start = index as f32 * source_len - source_offset;
+                    let start = tile_start.max(0.0);
+                    let end = (tile_start + source_len).min(target_len);
+                    if end > start {
+                        Some(Segment {
+                            source_start: source_start + (start - tile_start),
+                            source_len: end - start,
+                            target_start: start,
+                            target_len: end - start,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        }
+        TileWrap::Clamp => {
+            let start = scroll.max(0.0);
+            let end = (scroll + source_len).min(target_len);
+            if end > start {
+                vec![Segment {
+                    source_start: source_start + (start - scroll),
+                    source_len: end - start,
+                    target_start: start,
+                    target_len: end - start,
+                }]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct TimeScale {
+    value: Arc<AtomicU32>,
+}
+
+impl TimeScale {
+    pub fn new(scale: f32) -> Self {
+        Self {
+            value: Arc::new(AtomicU32::new(scale.to_bits())),
+        }
+    }
+
+    pub fn scale(&self) -> f32 {
+        f32::from_bits(self.value.load(Ordering::Relaxed))
+    }
+
+    pub fn set_scale(&self, scale: f32) {
+        self.value.store(scale.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+pub(crate) fn scale_duration(duration: Duration, scale: f32) -> Duration {
+    Duration::try_from_secs_f32(duration.as_secs_f32() * scale.abs()).unwrap_or(Duration::MAX)
+}
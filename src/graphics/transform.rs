@@ -0,0 +1,160 @@
+use tge::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform2D {
+    a: f32,
+    b: f32,
+    c: f32,
+    d: f32,
+    tx: f32,
+    ty: f32,
+}
+
+impl Transform2D {
+    pub fn identity() -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    pub fn translate(self, offset: impl Into<Vector>) -> Self {
+        let offset = offset.into();
+        Self {
+            tx: self.tx + offset.x,
+            ty: self.ty + offset.y,
+            ..self
+        }
+    }
+
+    pub fn rotate(self, angle: Angle) -> Self {
+        let (sin, cos) = angle.radians_value().sin_cos();
+        self.then(Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            tx: 0.0,
+            ty: 0.0,
+        })
+    }
+
+    pub fn scale(self, scale: impl Into<Vector>) -> Self {
+        let scale = scale.into();
+        self.then(Self {
+            a: scale.x,
+            b: 0.0,
+            c: 0.0,
+            d: scale.y,
+            tx: 0.0,
+            ty: 0.0,
+        })
+    }
+
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            tx: next.a * self.tx + next.c * self.ty + next.tx,
+            ty: next.b * self.tx + next.d * self.ty + next.ty,
+        }
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.a * self.d - self.b * self.c;
+        if determinant.abs() <= f32::EPSILON {
+            return None;
+        }
+        let a = self.d / determinant;
+        let b = -self.b / determinant;
+        let c = -self.c / determinant;
+        let d = self.a / determinant;
+        Some(Self {
+            a,
+            b,
+            c,
+            d,
+            tx: -(a * self.tx + c * self.ty),
+            ty: -(b * self.tx + d * self.ty),
+        })
+    }
+
+    pub fn transform_position(&self, position: impl Into<Position>) -> Position {
+        let position = position.into();
+        Position::new(
+            self.a * position.x + self.c * position.y + self.tx,
+            self.b * position.x + self.d * position.y + self.ty,
+        )
+    }
+
+    pub fn to_transform(&self) -> Transform {
+        let e = (self.a + self.d) / 2.0;
+        let f = (self.a - self.d) / 2.0;
+        let g = (self.b + self.c) / 2.0;
+        let h = (self.b - self.c) / 2.0;
+        let q = (e * e + h * h).sqrt();
+        let r = (f * f + g * g).sqrt();
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+        Transform::default()
+            .rotate(Angle::radians((a2 - a1) / 2.0))
+            .scale((q + r, q - r))
+            .rotate(Angle::radians((a2 + a1) / 2.0))
+            .translate((self.tx, self.ty))
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Transform2D> for Transform {
+    fn from(transform: Transform2D) -> Self {
+        transform.to_transform()
+    }
+}
+
+pub(crate) fn concat_transform(local: Transform2D, transform: Option<Transform2D>) -> Transform2D {
+    match transform {
+        Some(transform) => local.then(transform),
+        None => local,
+    }
+}
+
+pub(crate) fn to_transform(transform: Option<Transform2D>) -> Option<Transform> {
+    transform.map(Transform::from)
+}
+
+pub(crate) fn transform_position(transform: Option<Transform2D>, position: Position) -> Position {
+    match transform {
+        Some(transform) => transform.transform_position(position),
+        None => position,
+    }
+}
+
+pub(crate) fn transform_bounds(transform: Option<Transform2D>, rect: Region) -> Region {
+    let corners = [
+        Position::new(rect.x, rect.y),
+        Position::new(rect.x + rect.width, rect.y),
+        Position::new(rect.x + rect.width, rect.y + rect.height),
+        Position::new(rect.x, rect.y + rect.height),
+    ];
+    let mut min = Position::new(f32::INFINITY, f32::INFINITY);
+    let mut max = Position::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+    for corner in corners.iter() {
+        let corner = transform_position(transform, *corner);
+        min.x = min.x.min(corner.x);
+        min.y = min.y.min(corner.y);
+        max.x = max.x.max(corner.x);
+        max.y = max.y.max(corner.y);
+    }
+    Region::new(min.x, min.y, max.x - min.x, max.y - min.y)
+}
@@ -0,0 +1,242 @@
+use super::{RichLabel, Transform2D};
+use super::rich_label::{RichLayout, RichView};
+use crate::asset::{FontProvider, TextureRefProvider};
+use tge::prelude::*;
+use std::ops::Range;
+use std::time::Duration;
+
+pub type TypewriterFunc = Box<dyn FnMut(char)>;
+
+pub struct Typewriter {
+    label: RichLabel,
+    box_size: Size,
+    layout: RichLayout,
+    pages: Vec<Range<usize>>,
+    page_index: usize,
+    revealed: usize,
+    wait: f32,
+    chars_per_second: f32,
+    punctuation: String,
+    punctuation_delay: Duration,
+    on_char: Option<TypewriterFunc>,
+    paused: bool,
+}
+
+impl Typewriter {
+    pub fn new(provider: &(impl FontProvider + TextureRefProvider), mut label: RichLabel, box_size: impl Into<Size>) -> GameResult<Self> {
+        let box_size = box_size.into();
+        label.set_max_width(Some(box_size.width));
+        let layout = label.layout(provider)?;
+        let mut typewriter = Self {
+            label,
+            box_size,
+            layout,
+            pages: Vec::new(),
+            page_index: 0,
+            revealed: 0,
+            wait: 0.0,
+            chars_per_second: 30.0,
+            punctuation: ".,!?;:".into(),
+            punctuation_delay: Duration::from_millis(150),
+            on_char: None,
+            paused: false,
+        };
+        typewriter.paginate();
+        Ok(typewriter)
+    }
+
+    pub fn label(&self) -> &RichLabel {
+        &self.label
+    }
+
+    pub fn set_label(&mut self, provider: &(impl FontProvider + TextureRefProvider), mut label: RichLabel) -> GameResult {
+        label.set_max_width(Some(self.box_size.width));
+        self.layout = label.layout(provider)?;
+        self.label = label;
+        self.paginate();
+        Ok(())
+    }
+
+    pub fn set_markup(&mut self, provider: &(impl FontProvider + TextureRefProvider), markup: &str) -> GameResult {
+        let mut label = self.label.clone();
+        label.set_markup(markup)?;
+        self.set_label(provider, label)
+    }
+
+    pub fn box_size(&self) -> Size {
+        self.box_size
+    }
+
+    pub fn set_box_size(&mut self, provider: &(impl FontProvider + TextureRefProvider), box_size: impl Into<Size>) -> GameResult {
+        self.box_size = box_size.into();
+        self.set_label(provider, self.label.clone())
+    }
+
+    pub fn chars_per_second(&self) -> f32 {
+        self.chars_per_second
+    }
+
+    pub fn set_chars_per_second(&mut self, chars_per_second: f32) {
+        self.chars_per_second = chars_per_second;
+    }
+
+    pub fn punctuation(&self) -> &str {
+        &self.punctuation
+    }
+
+    pub fn set_punctuation(&mut self, punctuation: impl Into<String>) {
+        self.punctuation = punctuation.into();
+    }
+
+    pub fn punctuation_delay(&self) -> Duration {
+        self.punctuation_delay
+    }
+
+    pub fn set_punctuation_delay(&mut self, punctuation_delay: Duration) {
+        self.punctuation_delay = punctuation_delay;
+    }
+
+    pub fn set_on_char(&mut self, on_char: impl FnMut(char) + 'static) {
+        self.on_char = Some(Box::new(on_char));
+    }
+
+    pub fn clear_on_char(&mut self) {
+        self.on_char = None;
+    }
+
+    pub fn page_index(&self) -> usize {
+        self.page_index
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.page_index + 1 < self.pages.len()
+    }
+
+    pub fn page_chars_len(&self) -> usize {
+        self.page_glyphs().len()
+    }
+
+    pub fn revealed_chars_len(&self) -> usize {
+        self.revealed - self.page_glyphs().start
+    }
+
+    pub fn is_page_complete(&self) -> bool {
+        self.revealed >= self.page_glyphs().end
+    }
+
+    pub fn is_finished(&self) -> bool {
+        !self.has_next_page() && self.is_page_complete()
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn reset(&mut self) {
+        self.start_page(0);
+    }
+
+    pub fn skip(&mut self) {
+        self.revealed = self.page_glyphs().end;
+    }
+
+    pub fn next_page(&mut self) -> bool {
+        if self.has_next_page() {
+            self.start_page(self.page_index + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn advance(&mut self) -> bool {
+        if self.is_page_complete() {
+            self.next_page()
+        } else {
+            self.skip();
+            true
+        }
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        if self.paused || self.is_page_complete() {
+            return;
+        }
+        let end = self.page_glyphs().end;
+        self.wait -= delta_time.as_secs_f32();
+        while self.wait <= 0.0 && self.revealed < end {
+            let c = self.layout.glyphs[self.revealed];
+            self.revealed += 1;
+            if let Some(on_char) = &mut self.on_char {
+                on_char(c);
+            }
+            self.wait += self.char_delay(c);
+            if self.revealed < end {
+                self.wait += self.pause_at(self.revealed);
+            }
+        }
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &(impl FontProvider + TextureRefProvider), transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let view = RichView {
+            lines: self.pages[self.page_index].clone(),
+            visible_glyphs: self.revealed,
+            size: self.box_size,
+        };
+        self.label.draw_layout(graphics, provider, &self.layout, &view, transform.into())
+    }
+
+    fn paginate(&mut self) {
+        self.pages.clear();
+        let mut start = 0;
+        for end in 2..=self.layout.lines.len() {
+            if end - start > 1 && self.layout.lines_height(start..end) > self.box_size.height {
+                self.pages.push(start..end - 1);
+                start = end - 1;
+            }
+        }
+        self.pages.push(start..self.layout.lines.len());
+        self.start_page(0);
+    }
+
+    fn start_page(&mut self, page_index: usize) {
+        self.page_index = page_index;
+        self.revealed = self.page_glyphs().start;
+        self.wait = self.pause_at(self.revealed);
+    }
+
+    fn page_glyphs(&self) -> Range<usize> {
+        let lines = &self.layout.lines[self.pages[self.page_index].clone()];
+        match (lines.first(), lines.last()) {
+            (Some(first), Some(last)) => first.glyphs.start..last.glyphs.end,
+            _ => 0..0,
+        }
+    }
+
+    fn char_delay(&self, c: char) -> f32 {
+        let mut delay = if self.chars_per_second > 0.0 { 1.0 / self.chars_per_second } else { 0.0 };
+        if self.punctuation.contains(c) {
+            delay += self.punctuation_delay.as_secs_f32();
+        }
+        delay
+    }
+
+    fn pause_at(&self, glyph_index: usize) -> f32 {
+        self.layout.pauses.iter()
+            .filter(|(index, _)| *index == glyph_index)
+            .map(|(_, duration)| duration.as_secs_f32())
+            .sum()
+    }
+}
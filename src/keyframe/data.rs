@@ -0,0 +1,183 @@
+use super::{Interpolation, Keyframe, Track, Timeline};
+use crate::graphics::{PlayMode, RepeatCount, FinishBehavior};
+use crate::tween::Easing;
+use tge::prelude::*;
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PlayModeData {
+    Normal,
+    Reversed,
+    PingPong,
+    ReversedPingPong,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum FinishBehaviorData {
+    HoldLastFrame,
+    ResetToFirstFrame,
+    Hide,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum InterpolationData {
+    Name(String),
+    Bezier {
+        bezier: [f32; 4],
+    },
+}
+
+impl Default for InterpolationData {
+    fn default() -> Self {
+        Self::Name("linear".into())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyData<T> {
+    time: f32,
+    value: T,
+    #[serde(default)]
+    interpolation: InterpolationData,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct TimelineData {
+    play_mode: PlayModeData,
+    repeat_count: Option<usize>,
+    finish_behavior: FinishBehaviorData,
+    position: Vec<KeyData<[f32; 2]>>,
+    rotation: Vec<KeyData<f32>>,
+    scale: Vec<KeyData<[f32; 2]>>,
+    origin: Vec<KeyData<[f32; 2]>>,
+    color: Vec<KeyData<[f32; 4]>>,
+}
+
+impl Default for TimelineData {
+    fn default() -> Self {
+        Self {
+            play_mode: PlayModeData::Normal,
+            repeat_count: Some(1),
+            finish_behavior: FinishBehaviorData::HoldLastFrame,
+            position: Vec::new(),
+            rotation: Vec::new(),
+            scale: Vec::new(),
+            origin: Vec::new(),
+            color: Vec::new(),
+        }
+    }
+}
+
+impl Timeline {
+    pub fn from_json(json: &str) -> GameResult<Self> {
+        let data: TimelineData = serde_json::from_str(json).map_err(|error| GameError::RuntimeError(format!("invalid timeline: {}", error)))?;
+        let mut timeline = Self::new();
+        timeline.play_mode = match data.play_mode {
+            PlayModeData::Normal => PlayMode::Normal,
+            PlayModeData::Reversed => PlayMode::Reversed,
+            PlayModeData::PingPong => PlayMode::PingPong,
+            PlayModeData::ReversedPingPong => PlayMode::ReversedPingPong,
+        };
+        timeline.repeat_count = match data.repeat_count {
+            Some(repeat_count) => RepeatCount::Count(repeat_count),
+            None => RepeatCount::Infinite,
+        };
+        timeline.finish_behavior = match data.finish_behavior {
+            FinishBehaviorData::HoldLastFrame => FinishBehavior::HoldLastFrame,
+            FinishBehaviorData::ResetToFirstFrame => FinishBehavior::ResetToFirstFrame,
+            FinishBehaviorData::Hide => FinishBehavior::Hide,
+        };
+        timeline.position = decode_track(data.position, |[x, y]| Vector::new(x, y))?;
+        timeline.rotation = decode_track(data.rotation, Angle::degrees)?;
+        timeline.scale = decode_track(data.scale, |[x, y]| Vector::new(x, y))?;
+        timeline.origin = decode_track(data.origin, |[x, y]| Vector::new(x, y))?;
+        timeline.color = decode_track(data.color, |[red, green, blue, alpha]| Color::new(red, green, blue, alpha))?;
+        if timeline.checked_period_duration().is_none() {
+            return Err(GameError::RuntimeError(format!("invalid timeline: duration too long: {:?}", timeline.duration())));
+        }
+        if let RepeatCount::Count(repeat_count) = timeline.repeat_count {
+            if timeline.checked_total_duration(repeat_count).is_none() {
+                return Err(GameError::RuntimeError(format!("invalid timeline: invalid repeat count: {}", repeat_count)));
+            }
+        }
+        Ok(timeline)
+    }
+
+    pub fn to_json(&self) -> GameResult<String> {
+        let play_mode = match self.play_mode {
+            PlayMode::Normal | PlayMode::Random => PlayModeData::Normal,
+            PlayMode::Reversed => PlayModeData::Reversed,
+            PlayMode::PingPong => PlayModeData::PingPong,
+            PlayMode::ReversedPingPong => PlayModeData::ReversedPingPong,
+        };
+        let data = TimelineData {
+            play_mode,
+            repeat_count: match self.repeat_count {
+                RepeatCount::Count(repeat_count) => Some(repeat_count),
+                RepeatCount::Infinite => None,
+            },
+            finish_behavior: match self.finish_behavior {
+                FinishBehavior::HoldLastFrame => FinishBehaviorData::HoldLastFrame,
+                FinishBehavior::ResetToFirstFrame => FinishBehaviorData::ResetToFirstFrame,
+                FinishBehavior::Hide => FinishBehaviorData::Hide,
+            },
+            position: encode_track(&self.position, |value| [value.x, value.y])?,
+            rotation: encode_track(&self.rotation, |value| value.degrees_value())?,
+            scale: encode_track(&self.scale, |value| [value.x, value.y])?,
+            origin: encode_track(&self.origin, |value| [value.x, value.y])?,
+            color: encode_track(&self.color, |value| [value.red, value.green, value.blue, value.alpha])?,
+        };
+        serde_json::to_string_pretty(&data).map_err(|error| GameError::RuntimeError(format!("invalid timeline: {}", error)))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> GameResult<Self> {
+        let json = fs::read_to_string(path).map_err(|error| GameError::RuntimeError(format!("can not read timeline: {}", error)))?;
+        Self::from_json(&json)
+    }
+}
+
+fn decode_track<D, T>(keys: Vec<KeyData<D>>, f: impl Fn(D) -> T) -> GameResult<Track<T>> {
+    let mut track = Track::new();
+    for key in keys {
+        let time = Duration::try_from_secs_f32(key.time)
+            .map_err(|_| GameError::RuntimeError(format!("invalid timeline: invalid key time: {}", key.time)))?;
+        let interpolation = match key.interpolation {
+            InterpolationData::Name(name) => match name.as_str() {
+                "step" => Interpolation::Step,
+                "linear" => Interpolation::Linear,
+                _ => Interpolation::Eased(Easing::by_name(&name)
+                    .ok_or_else(|| GameError::RuntimeError(format!("invalid timeline: unknown interpolation: {}", name)))?),
+            },
+            InterpolationData::Bezier { bezier: [x1, y1, x2, y2] } => Interpolation::Bezier(x1, y1, x2, y2),
+        };
+        track.push_key(Keyframe::new(time, f(key.value)).interpolation(interpolation));
+    }
+    Ok(track)
+}
+
+fn encode_track<T: Copy, D>(track: &Track<T>, f: impl Fn(T) -> D) -> GameResult<Vec<KeyData<D>>> {
+    track.keys().iter()
+        .map(|key| {
+            let interpolation = match key.interpolation {
+                Interpolation::Step => InterpolationData::Name("step".into()),
+                Interpolation::Linear => InterpolationData::Name("linear".into()),
+                Interpolation::Eased(easing) => InterpolationData::Name(easing.name()
+                    .ok_or_else(|| GameError::RuntimeError("invalid timeline: custom easing can not be serialized".into()))?
+                    .into()),
+                Interpolation::Bezier(x1, y1, x2, y2) => InterpolationData::Bezier { bezier: [x1, y1, x2, y2] },
+            };
+            Ok(KeyData {
+                time: key.time.as_secs_f32(),
+                value: f(key.value),
+                interpolation,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+use crate::tween::Easing;
+
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Interpolation {
+    Step,
+    #[default]
+    Linear,
+    Eased(Easing),
+    Bezier(f32, f32, f32, f32),
+}
+
+impl Interpolation {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Step => if t >= 1.0 { 1.0 } else { 0.0 },
+            Self::Linear => t,
+            Self::Eased(easing) => easing.apply(t),
+            Self::Bezier(x1, y1, x2, y2) => cubic_bezier(t, *x1, *y1, *x2, *y2),
+        }
+    }
+}
+
+fn bezier_axis(s: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+}
+
+fn bezier_axis_slope(s: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+fn cubic_bezier(x: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+    let x1 = x1.clamp(0.0, 1.0);
+    let x2 = x2.clamp(0.0, 1.0);
+    let mut s = x;
+    for _ in 0..8 {
+        let error = bezier_axis(s, x1, x2) - x;
+        if error.abs() < 1e-6 {
+            return bezier_axis(s, y1, y2);
+        }
+        let slope = bezier_axis_slope(s, x1, x2);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s = (s - error / slope).clamp(0.0, 1.0);
+    }
+    let mut low = 0.0;
+    let mut high = 1.0;
+    s = x;
+    for _ in 0..32 {
+        let value = bezier_axis(s, x1, x2);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    bezier_axis(s, y1, y2)
+}
@@ -0,0 +1,8 @@
+mod interpolation;
+mod track;
+mod timeline;
+mod data;
+
+pub use interpolation::Interpolation;
+pub use track::{Keyframe, Track};
+pub use timeline::{TimelineSample, Timeline};
@@ -0,0 +1,345 @@
+use super::Track;
+use crate::graphics::{PlayMode, RepeatCount, FinishBehavior, Transform2D, scale_duration};
+use tge::prelude::*;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TimelineSample {
+    pub position: Vector,
+    pub rotation: Angle,
+    pub scale: Vector,
+    pub origin: Vector,
+    pub color: Color,
+}
+
+impl TimelineSample {
+    pub fn transform(&self) -> Transform2D {
+        Transform2D::default()
+            .translate((-self.origin.x, -self.origin.y))
+            .scale((self.scale.x, self.scale.y))
+            .rotate(self.rotation)
+            .translate((self.position.x, self.position.y))
+    }
+}
+
+impl Default for TimelineSample {
+    fn default() -> Self {
+        Self {
+            position: Vector::zero(),
+            rotation: Angle::zero(),
+            scale: Vector::new(1.0, 1.0),
+            origin: Vector::zero(),
+            color: Color::WHITE,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    pub(super) position: Track<Vector>,
+    pub(super) rotation: Track<Angle>,
+    pub(super) scale: Track<Vector>,
+    pub(super) origin: Track<Vector>,
+    pub(super) color: Track<Color>,
+    pub(super) play_mode: PlayMode,
+    pub(super) repeat_count: RepeatCount,
+    pub(super) finish_behavior: FinishBehavior,
+    elapsed: Duration,
+    speed: f32,
+    paused: bool,
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Self {
+            position: Track::new(),
+            rotation: Track::new(),
+            scale: Track::new(),
+            origin: Track::new(),
+            color: Track::new(),
+            play_mode: PlayMode::default(),
+            repeat_count: RepeatCount::Count(1),
+            finish_behavior: FinishBehavior::HoldLastFrame,
+            elapsed: Duration::new(0, 0),
+            speed: 1.0,
+            paused: false,
+        }
+    }
+
+    pub fn position_track(&self) -> &Track<Vector> {
+        &self.position
+    }
+
+    pub fn position_track_mut(&mut self) -> &mut Track<Vector> {
+        &mut self.position
+    }
+
+    pub fn set_position_track(&mut self, track: Track<Vector>) {
+        self.position = track;
+    }
+
+    pub fn rotation_track(&self) -> &Track<Angle> {
+        &self.rotation
+    }
+
+    pub fn rotation_track_mut(&mut self) -> &mut Track<Angle> {
+        &mut self.rotation
+    }
+
+    pub fn set_rotation_track(&mut self, track: Track<Angle>) {
+        self.rotation = track;
+    }
+
+    pub fn scale_track(&self) -> &Track<Vector> {
+        &self.scale
+    }
+
+    pub fn scale_track_mut(&mut self) -> &mut Track<Vector> {
+        &mut self.scale
+    }
+
+    pub fn set_scale_track(&mut self, track: Track<Vector>) {
+        self.scale = track;
+    }
+
+    pub fn origin_track(&self) -> &Track<Vector> {
+        &self.origin
+    }
+
+    pub fn origin_track_mut(&mut self) -> &mut Track<Vector> {
+        &mut self.origin
+    }
+
+    pub fn set_origin_track(&mut self, track: Track<Vector>) {
+        self.origin = track;
+    }
+
+    pub fn color_track(&self) -> &Track<Color> {
+        &self.color
+    }
+
+    pub fn color_track_mut(&mut self) -> &mut Track<Color> {
+        &mut self.color
+    }
+
+    pub fn set_color_track(&mut self, track: Track<Color>) {
+        self.color = track;
+    }
+
+    pub fn play_mode(&self) -> PlayMode {
+        self.play_mode
+    }
+
+    pub fn set_play_mode(&mut self, play_mode: PlayMode) {
+        self.play_mode = match play_mode {
+            PlayMode::Random => PlayMode::Normal,
+            play_mode => play_mode,
+        };
+    }
+
+    pub fn repeat_count(&self) -> RepeatCount {
+        self.repeat_count
+    }
+
+    pub fn set_repeat_count(&mut self, repeat_count: RepeatCount) {
+        self.repeat_count = repeat_count;
+    }
+
+    pub fn finish_behavior(&self) -> FinishBehavior {
+        self.finish_behavior
+    }
+
+    pub fn set_finish_behavior(&mut self, finish_behavior: FinishBehavior) {
+        self.finish_behavior = finish_behavior;
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::new(0, 0);
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.position.duration()
+            .max(self.rotation.duration())
+            .max(self.scale.duration())
+            .max(self.origin.duration())
+            .max(self.color.duration())
+    }
+
+    pub fn period_duration(&self) -> Duration {
+        self.checked_period_duration().unwrap_or(Duration::MAX)
+    }
+
+    pub fn total_duration(&self) -> Option<Duration> {
+        match self.repeat_count {
+            RepeatCount::Infinite => None,
+            RepeatCount::Count(repeat_count) => Some(self.checked_total_duration(repeat_count).unwrap_or(Duration::MAX)),
+        }
+    }
+
+    pub(super) fn checked_period_duration(&self) -> Option<Duration> {
+        match self.play_mode {
+            PlayMode::PingPong | PlayMode::ReversedPingPong => self.duration().checked_mul(2),
+            PlayMode::Normal | PlayMode::Reversed | PlayMode::Random => Some(self.duration()),
+        }
+    }
+
+    pub(super) fn checked_total_duration(&self, repeat_count: usize) -> Option<Duration> {
+        self.checked_period_duration()?.checked_mul(u32::try_from(repeat_count).ok()?)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn seek(&mut self, elapsed: Duration) {
+        self.elapsed = match self.total_duration() {
+            Some(total_duration) => elapsed.min(total_duration),
+            None => elapsed,
+        };
+    }
+
+    pub fn play_count(&self) -> usize {
+        let period_duration = self.period_duration().as_nanos();
+        if period_duration == 0 {
+            return 0;
+        }
+        let play_count = (self.elapsed.as_nanos() / period_duration) as usize;
+        match self.repeat_count {
+            RepeatCount::Infinite => play_count,
+            RepeatCount::Count(repeat_count) => play_count.min(repeat_count),
+        }
+    }
+
+    pub fn progress(&self) -> f32 {
+        let period_duration = self.period_duration();
+        if period_duration == Duration::new(0, 0) {
+            return 0.0;
+        }
+        if self.is_finished() {
+            return 1.0;
+        }
+        let local = self.elapsed.as_nanos() % period_duration.as_nanos();
+        (local as f64 / period_duration.as_nanos() as f64) as f32
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match self.total_duration() {
+            Some(total_duration) => self.elapsed >= total_duration,
+            None => false,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        !(self.is_finished() && self.finish_behavior == FinishBehavior::Hide)
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        if self.paused || self.is_finished() || self.speed == 0.0 || !self.speed.is_finite() {
+            return;
+        }
+        let delta_time = scale_duration(delta_time, self.speed);
+        let elapsed = if self.speed > 0.0 {
+            self.elapsed.saturating_add(delta_time)
+        } else {
+            match (self.elapsed.checked_sub(delta_time), self.repeat_count) {
+                (Some(elapsed), _) => elapsed,
+                (None, RepeatCount::Infinite) => {
+                    let period_duration = self.period_duration().as_nanos();
+                    if period_duration == 0 {
+                        return;
+                    }
+                    let rewind = (delta_time - self.elapsed).as_nanos() % period_duration;
+                    duration_from_nanos((period_duration - rewind) % period_duration)
+                }
+                (None, RepeatCount::Count(_)) => Duration::new(0, 0),
+            }
+        };
+        self.seek(elapsed);
+    }
+
+    pub fn time(&self) -> Duration {
+        let duration = self.duration();
+        let period_duration = self.period_duration().as_nanos();
+        if period_duration == 0 {
+            return Duration::new(0, 0);
+        }
+        let local = if self.is_finished() {
+            match self.finish_behavior {
+                FinishBehavior::ResetToFirstFrame => 0,
+                FinishBehavior::HoldLastFrame | FinishBehavior::Hide => period_duration,
+            }
+        } else {
+            self.elapsed.as_nanos() % period_duration
+        };
+        let local = duration_from_nanos(local);
+        let ping_pong = |local: Duration| if local <= duration { local } else { duration.saturating_sub(local - duration) };
+        match self.play_mode {
+            PlayMode::Normal | PlayMode::Random => local,
+            PlayMode::Reversed => duration - local,
+            PlayMode::PingPong => ping_pong(local),
+            PlayMode::ReversedPingPong => duration - ping_pong(local),
+        }
+    }
+
+    pub fn sample_at(&self, time: Duration) -> TimelineSample {
+        let default = TimelineSample::default();
+        TimelineSample {
+            position: self.position.value_at(time).unwrap_or(default.position),
+            rotation: self.rotation.value_at(time).unwrap_or(default.rotation),
+            scale: self.scale.value_at(time).unwrap_or(default.scale),
+            origin: self.origin.value_at(time).unwrap_or(default.origin),
+            color: self.color.value_at(time).unwrap_or(default.color),
+        }
+    }
+
+    pub fn transform_at(&self, time: Duration) -> Transform2D {
+        self.sample_at(time).transform()
+    }
+
+    pub fn color_at(&self, time: Duration) -> Color {
+        self.color.value_at(time).unwrap_or(Color::WHITE)
+    }
+
+    pub fn sample(&self) -> TimelineSample {
+        self.sample_at(self.time())
+    }
+
+    pub fn transform(&self) -> Transform2D {
+        self.transform_at(self.time())
+    }
+
+    pub fn color(&self) -> Color {
+        self.color_at(self.time())
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn duration_from_nanos(nanos: u128) -> Duration {
+    Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32)
+}
@@ -0,0 +1,95 @@
+use super::Interpolation;
+use crate::tween::Lerp;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Keyframe<T> {
+    pub time: Duration,
+    pub value: T,
+    pub interpolation: Interpolation,
+}
+
+impl<T> Keyframe<T> {
+    pub fn new(time: Duration, value: T) -> Self {
+        Self {
+            time,
+            value,
+            interpolation: Interpolation::default(),
+        }
+    }
+
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Track<T> {
+    keys: Vec<Keyframe<T>>,
+}
+
+impl<T> Track<T> {
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+        }
+    }
+
+    pub fn keys(&self) -> &[Keyframe<T>] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.keys.last().map_or(Duration::new(0, 0), |key| key.time)
+    }
+
+    pub fn push_key(&mut self, key: Keyframe<T>) {
+        let index = self.keys.iter().position(|other| other.time > key.time).unwrap_or(self.keys.len());
+        self.keys.insert(index, key);
+    }
+
+    pub fn key(mut self, time: Duration, value: T, interpolation: Interpolation) -> Self {
+        self.push_key(Keyframe::new(time, value).interpolation(interpolation));
+        self
+    }
+
+    pub fn remove_key(&mut self, index: usize) -> Keyframe<T> {
+        self.keys.remove(index)
+    }
+}
+
+impl<T: Lerp> Track<T> {
+    pub fn value_at(&self, time: Duration) -> Option<T> {
+        let index = self.keys.iter().position(|key| key.time > time).unwrap_or(self.keys.len());
+        if index == 0 {
+            return self.keys.first().map(|key| key.value);
+        }
+        let start = &self.keys[index - 1];
+        match self.keys.get(index) {
+            Some(end) => {
+                let t = (time - start.time).as_secs_f64() / (end.time - start.time).as_secs_f64();
+                Some(start.value.lerp(end.value, start.interpolation.apply(t as f32)))
+            }
+            None => Some(start.value),
+        }
+    }
+}
+
+impl<T> Default for Track<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,8 @@
 pub mod event;
 pub mod asset;
 pub mod graphics;
+pub mod tween;
+pub mod actions;
+pub mod particles;
+pub mod tilemap;
+pub mod keyframe;
@@ -0,0 +1,144 @@
+use super::Curve;
+use tge::prelude::*;
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Burst {
+    pub time: f32,
+    pub count: usize,
+    #[serde(default)]
+    pub interval: Option<f32>,
+}
+
+impl Burst {
+    pub fn new(time: f32, count: usize) -> Self {
+        Self {
+            time,
+            count,
+            interval: None,
+        }
+    }
+
+    pub(crate) fn occurrences(&self, elapsed: f32, period: Option<f32>) -> usize {
+        if elapsed < self.time {
+            return 0;
+        }
+        match self.interval {
+            Some(interval) if interval > 0.0 => {
+                let occurrences = ((elapsed - self.time) / interval).floor() as usize + 1;
+                match period {
+                    Some(period) if self.time < period => occurrences.min(((period - self.time) / interval).ceil() as usize),
+                    Some(_) => 1,
+                    None => occurrences,
+                }
+            }
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParticleConfig {
+    pub max_particles: usize,
+    pub duration: Option<f32>,
+    pub looping: bool,
+    pub emission_rate: f32,
+    pub bursts: Vec<Burst>,
+    pub lifetime: (f32, f32),
+    pub spawn_radius: f32,
+    pub direction: f32,
+    pub spread: f32,
+    pub speed: (f32, f32),
+    #[serde(with = "vector_serde")]
+    pub gravity: Vector,
+    pub damping: f32,
+    pub rotation: (f32, f32),
+    pub angular_velocity: (f32, f32),
+    pub scale_over_lifetime: Curve<f32>,
+    pub rotation_over_lifetime: Curve<f32>,
+    #[serde(with = "color_curve_serde")]
+    pub color_over_lifetime: Curve<Color>,
+}
+
+impl ParticleConfig {
+    pub fn new() -> Self {
+        Self {
+            max_particles: 256,
+            duration: None,
+            looping: false,
+            emission_rate: 10.0,
+            bursts: Vec::new(),
+            lifetime: (1.0, 1.0),
+            spawn_radius: 0.0,
+            direction: -90.0,
+            spread: 360.0,
+            speed: (50.0, 50.0),
+            gravity: Vector::zero(),
+            damping: 0.0,
+            rotation: (0.0, 0.0),
+            angular_velocity: (0.0, 0.0),
+            scale_over_lifetime: Curve::constant(1.0),
+            rotation_over_lifetime: Curve::constant(0.0),
+            color_over_lifetime: Curve::constant(Color::WHITE),
+        }
+    }
+
+    pub fn from_json(json: &str) -> GameResult<Self> {
+        serde_json::from_str(json).map_err(|error| GameError::RuntimeError(format!("invalid particle config: {}", error)))
+    }
+
+    pub fn to_json(&self) -> GameResult<String> {
+        serde_json::to_string_pretty(self).map_err(|error| GameError::RuntimeError(format!("invalid particle config: {}", error)))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> GameResult<Self> {
+        let json = fs::read_to_string(path).map_err(|error| GameError::RuntimeError(format!("can not read particle config: {}", error)))?;
+        Self::from_json(&json)
+    }
+}
+
+impl Default for ParticleConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+mod vector_serde {
+    use tge::prelude::*;
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<S: Serializer>(vector: &Vector, serializer: S) -> Result<S::Ok, S::Error> {
+        (vector.x, vector.y).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vector, D::Error> {
+        let (x, y) = <(f32, f32)>::deserialize(deserializer)?;
+        Ok(Vector::new(x, y))
+    }
+}
+
+mod color_curve_serde {
+    use super::Curve;
+    use tge::prelude::*;
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+    use std::convert::TryFrom;
+
+    pub fn serialize<S: Serializer>(curve: &Curve<Color>, serializer: S) -> Result<S::Ok, S::Error> {
+        let keys: Vec<(f32, [f32; 4])> = curve.keys().iter()
+            .map(|(time, color)| (*time, [color.red, color.green, color.blue, color.alpha]))
+            .collect();
+        keys.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Curve<Color>, D::Error> {
+        let keys = Vec::<(f32, [f32; 4])>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(time, [red, green, blue, alpha])| (time, Color::new(red, green, blue, alpha)))
+            .collect::<Vec<_>>();
+        Curve::try_from(keys).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::tween::Lerp;
+use serde::{Serialize, Deserialize};
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<(f32, T)>", into = "Vec<(f32, T)>")]
+#[serde(bound(serialize = "T: Clone + Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct Curve<T> {
+    keys: Vec<(f32, T)>,
+}
+
+impl<T> Curve<T> {
+    pub fn keys(&self) -> &[(f32, T)] {
+        &self.keys
+    }
+}
+
+impl<T: Lerp> Curve<T> {
+    pub fn constant(value: T) -> Self {
+        Self {
+            keys: vec![(0.0, value)],
+        }
+    }
+
+    pub fn linear(from: T, to: T) -> Self {
+        Self {
+            keys: vec![(0.0, from), (1.0, to)],
+        }
+    }
+
+    pub fn push_key(&mut self, time: f32, value: T) {
+        let index = self.keys.iter().position(|(key_time, _)| *key_time > time).unwrap_or(self.keys.len());
+        self.keys.insert(index, (time, value));
+    }
+
+    pub fn value_at(&self, time: f32) -> T {
+        let index = self.keys.iter().position(|(key_time, _)| *key_time > time).unwrap_or(self.keys.len());
+        if index == 0 {
+            return self.keys[0].1;
+        }
+        let (start_time, start) = self.keys[index - 1];
+        match self.keys.get(index) {
+            Some((end_time, end)) => start.lerp(*end, (time - start_time) / (end_time - start_time)),
+            None => start,
+        }
+    }
+}
+
+impl<T> TryFrom<Vec<(f32, T)>> for Curve<T> {
+    type Error = String;
+
+    fn try_from(mut keys: Vec<(f32, T)>) -> Result<Self, Self::Error> {
+        if keys.is_empty() {
+            return Err("curve has no keys".into());
+        }
+        keys.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(Self { keys })
+    }
+}
+
+impl<T> From<Curve<T>> for Vec<(f32, T)> {
+    fn from(curve: Curve<T>) -> Self {
+        curve.keys
+    }
+}
@@ -0,0 +1,210 @@
+use super::{Particle, ParticleConfig};
+use crate::graphics::random::random_unit;
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct ParticleEmitter {
+    config: ParticleConfig,
+    position: Position,
+    seed: u64,
+    spawn_count: u64,
+    particles: Vec<Particle>,
+    elapsed: f32,
+    emit_accumulator: f32,
+    emitting: bool,
+}
+
+impl ParticleEmitter {
+    pub fn new(config: ParticleConfig, seed: u64) -> Self {
+        Self {
+            config,
+            position: Position::zero(),
+            seed,
+            spawn_count: 0,
+            particles: Vec::new(),
+            elapsed: 0.0,
+            emit_accumulator: 0.0,
+            emitting: true,
+        }
+    }
+
+    pub fn config(&self) -> &ParticleConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut ParticleConfig {
+        &mut self.config
+    }
+
+    pub fn set_config(&mut self, config: ParticleConfig) {
+        self.config = config;
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: impl Into<Position>) {
+        self.position = position.into();
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.spawn_count = 0;
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::try_from_secs_f32(self.elapsed).unwrap_or(Duration::MAX)
+    }
+
+    pub fn is_emitting(&self) -> bool {
+        self.emitting
+    }
+
+    pub fn start(&mut self) {
+        self.elapsed = 0.0;
+        self.emit_accumulator = 0.0;
+        self.emitting = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.emitting = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        !self.emitting && self.particles.is_empty()
+    }
+
+    pub fn burst(&mut self, count: usize) {
+        let count = count.min(self.config.max_particles.saturating_sub(self.particles.len()));
+        for _ in 0..count {
+            self.spawn();
+        }
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        let delta_time_f32 = delta_time.as_secs_f32();
+        self.update_particles(delta_time, delta_time_f32);
+        if self.emitting {
+            self.emit(delta_time_f32);
+        }
+    }
+
+    fn update_particles(&mut self, delta_time: Duration, delta_time_f32: f32) {
+        let config = &self.config;
+        let damping = (-config.damping * delta_time_f32).exp();
+        for particle in &mut self.particles {
+            particle.age = particle.age.saturating_add(delta_time);
+            if !particle.is_alive() {
+                continue;
+            }
+            particle.velocity = Vector::new(
+                (particle.velocity.x + config.gravity.x * delta_time_f32) * damping,
+                (particle.velocity.y + config.gravity.y * delta_time_f32) * damping,
+            );
+            particle.position = Position::new(
+                particle.position.x + particle.velocity.x * delta_time_f32,
+                particle.position.y + particle.velocity.y * delta_time_f32,
+            );
+            particle.base_rotation += particle.angular_velocity * delta_time_f32;
+            apply_curves(config, particle);
+        }
+        self.particles.retain(|particle| particle.is_alive());
+    }
+
+    fn emit(&mut self, mut delta_time_f32: f32) {
+        while delta_time_f32 > 0.0 && self.emitting {
+            let step = match self.config.duration {
+                Some(duration) => delta_time_f32.min((duration - self.elapsed).max(0.0)),
+                None => delta_time_f32,
+            };
+            let start = self.elapsed;
+            self.elapsed += step;
+            delta_time_f32 -= step;
+            let period = self.config.duration.filter(|_| self.config.looping);
+            let bursts = self.config.bursts.iter()
+                .map(|burst| {
+                    let before = if start > 0.0 { burst.occurrences(start, period) } else { 0 };
+                    (burst.occurrences(self.elapsed, period) - before).saturating_mul(burst.count)
+                })
+                .fold(0, usize::saturating_add);
+            self.burst(bursts);
+            self.emit_accumulator += self.config.emission_rate * step;
+            let count = self.emit_accumulator.floor();
+            self.emit_accumulator -= count;
+            self.burst(count as usize);
+            if let Some(duration) = self.config.duration {
+                if self.elapsed >= duration {
+                    if self.config.looping && duration > 0.0 {
+                        self.elapsed = 0.0;
+                    } else {
+                        self.emitting = false;
+                    }
+                }
+            }
+        }
+    }
+
+    fn spawn(&mut self) {
+        if self.particles.len() >= self.config.max_particles {
+            return;
+        }
+        let seed = self.seed;
+        let base = self.spawn_count.wrapping_mul(8);
+        self.spawn_count += 1;
+        let random = |channel: u64| random_unit(seed, base.wrapping_add(channel));
+        let config = &self.config;
+        let spawn_distance = config.spawn_radius * random(0).sqrt();
+        let spawn_angle = random(1) * std::f32::consts::PI * 2.0;
+        let direction = (config.direction + (random(2) - 0.5) * config.spread).to_radians();
+        let speed = pick(config.speed, random(3));
+        let mut particle = Particle {
+            position: Position::new(
+                self.position.x + spawn_distance * spawn_angle.cos(),
+                self.position.y + spawn_distance * spawn_angle.sin(),
+            ),
+            velocity: Vector::new(speed * direction.cos(), speed * direction.sin()),
+            base_rotation: pick(config.rotation, random(4)),
+            angular_velocity: pick(config.angular_velocity, random(5)),
+            age: Duration::new(0, 0),
+            lifetime: Duration::try_from_secs_f32(pick(config.lifetime, random(6)).max(0.0)).unwrap_or(Duration::MAX),
+            rotation: Angle::zero(),
+            scale: 1.0,
+            color: Color::WHITE,
+        };
+        apply_curves(config, &mut particle);
+        self.particles.push(particle);
+    }
+}
+
+fn pick((min, max): (f32, f32), unit: f32) -> f32 {
+    min + (max - min) * unit
+}
+
+fn apply_curves(config: &ParticleConfig, particle: &mut Particle) {
+    let progress = particle.progress();
+    particle.rotation = Angle::degrees(particle.base_rotation + config.rotation_over_lifetime.value_at(progress));
+    particle.scale = config.scale_over_lifetime.value_at(progress);
+    particle.color = config.color_over_lifetime.value_at(progress);
+}
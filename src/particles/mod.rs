@@ -0,0 +1,11 @@
+mod curve;
+mod config;
+mod particle;
+mod emitter;
+mod sprite;
+
+pub use curve::Curve;
+pub use config::{Burst, ParticleConfig};
+pub use particle::Particle;
+pub use emitter::ParticleEmitter;
+pub use sprite::ParticleSprite;
@@ -0,0 +1,64 @@
+use crate::graphics::Transform2D;
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Particle {
+    pub(crate) position: Position,
+    pub(crate) velocity: Vector,
+    pub(crate) base_rotation: f32,
+    pub(crate) angular_velocity: f32,
+    pub(crate) age: Duration,
+    pub(crate) lifetime: Duration,
+    pub(crate) rotation: Angle,
+    pub(crate) scale: f32,
+    pub(crate) color: Color,
+}
+
+impl Particle {
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn velocity(&self) -> Vector {
+        self.velocity
+    }
+
+    pub fn rotation(&self) -> Angle {
+        self.rotation
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn age(&self) -> Duration {
+        self.age
+    }
+
+    pub fn lifetime(&self) -> Duration {
+        self.lifetime
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.lifetime.as_nanos() == 0 {
+            return 1.0;
+        }
+        (self.age.as_secs_f32() / self.lifetime.as_secs_f32()).min(1.0)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.age < self.lifetime
+    }
+
+    pub fn transform(&self) -> Transform2D {
+        Transform2D::default()
+            .scale((self.scale, self.scale))
+            .rotate(self.rotation)
+            .translate(self.position)
+    }
+}
@@ -0,0 +1,42 @@
+use super::ParticleEmitter;
+use crate::graphics::{Frame, Animation};
+use crate::graphics::transform::{Transform2D, concat_transform};
+use crate::asset::TextureRefProvider;
+use tge::prelude::*;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub enum ParticleSprite {
+    Frame(Frame),
+    Animation(Animation),
+}
+
+impl ParticleSprite {
+    pub fn frame_at(&self, age: Duration) -> &Frame {
+        match self {
+            Self::Frame(frame) => frame,
+            Self::Animation(animation) => &animation.frames()[animation.frame_index_at(age)],
+        }
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, emitter: &ParticleEmitter, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let transform = transform.into();
+        for particle in emitter.particles() {
+            self.frame_at(particle.age())
+                .draw_with(graphics, provider, particle.color(), (false, false), Some(concat_transform(particle.transform(), transform)))?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Frame> for ParticleSprite {
+    fn from(frame: Frame) -> Self {
+        Self::Frame(frame)
+    }
+}
+
+impl From<Animation> for ParticleSprite {
+    fn from(animation: Animation) -> Self {
+        Self::Animation(animation)
+    }
+}
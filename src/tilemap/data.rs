@@ -0,0 +1,105 @@
+use super::Tile;
+use crate::graphics::color::{HexColorOrder, parse_hex_color};
+use tge::prelude::*;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use std::io::Read;
+use std::path::Path;
+
+pub(crate) fn decode_tiles(data: &str, encoding: Option<&str>, compression: Option<&str>, len: usize) -> GameResult<Vec<Tile>> {
+    let raws = match encoding {
+        Some("csv") => data.split(',')
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse::<u32>().map_err(|_| GameError::RuntimeError(format!("invalid tile data: {}", value))))
+            .collect::<GameResult<Vec<_>>>()?,
+        Some("base64") => {
+            let bytes = decode_base64(data)?;
+            let bytes = decompress(bytes, compression)?;
+            bytes.chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect()
+        }
+        Some(encoding) => return Err(GameError::RuntimeError(format!("unsupported tile data encoding: {}", encoding))),
+        None => return Err(GameError::RuntimeError("no tile data encoding".into())),
+    };
+    check_len(raws.into_iter().map(Tile::from_raw).collect(), len)
+}
+
+pub(crate) fn check_len(tiles: Vec<Tile>, len: usize) -> GameResult<Vec<Tile>> {
+    if tiles.len() != len {
+        return Err(GameError::RuntimeError(format!("tile data length mismatch: expected {}, got {}", len, tiles.len())));
+    }
+    Ok(tiles)
+}
+
+fn decompress(bytes: Vec<u8>, compression: Option<&str>) -> GameResult<Vec<u8>> {
+    let mut decompressed = Vec::new();
+    let result = match compression {
+        None | Some("") => return Ok(bytes),
+        Some("zlib") => ZlibDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed),
+        Some("gzip") => GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed),
+        Some(compression) => return Err(GameError::RuntimeError(format!("unsupported tile data compression: {}", compression))),
+    };
+    result.map_err(|error| GameError::RuntimeError(format!("invalid compressed tile data: {}", error)))?;
+    Ok(decompressed)
+}
+
+fn decode_base64(data: &str) -> GameResult<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in data.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => return Err(GameError::RuntimeError("invalid base64 tile data".into())),
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+pub(crate) fn parse_color(value: &str) -> GameResult<Color> {
+    parse_hex_color(value.trim_start_matches('#'), HexColorOrder::Argb)
+        .ok_or_else(|| GameError::RuntimeError(format!("invalid color: {}", value)))
+}
+
+pub(crate) fn check_map_tile_size(tile_size: (f32, f32)) -> GameResult<()> {
+    let positive = |value: f32| value.is_finite() && value > 0.0;
+    if !positive(tile_size.0) || !positive(tile_size.1) {
+        return Err(GameError::RuntimeError(format!("invalid map: tile size {}x{}", tile_size.0, tile_size.1)));
+    }
+    Ok(())
+}
+
+pub(crate) fn layer_len(cols: usize, rows: usize) -> GameResult<usize> {
+    cols.checked_mul(rows).ok_or_else(|| GameError::RuntimeError(format!("invalid tile layer size: {}x{}", cols, rows)))
+}
+
+pub(crate) fn check_tileset_size(tile_size: (f32, f32), image_size: (f32, f32), margin: f32, spacing: f32) -> GameResult<()> {
+    let positive = |value: f32| value.is_finite() && value > 0.0;
+    let non_negative = |value: f32| value.is_finite() && value >= 0.0;
+    if !positive(tile_size.0) || !positive(tile_size.1) {
+        return Err(GameError::RuntimeError(format!("invalid tileset: tile size {}x{}", tile_size.0, tile_size.1)));
+    }
+    if !positive(image_size.0) || !positive(image_size.1) {
+        return Err(GameError::RuntimeError(format!("invalid tileset: image size {}x{}", image_size.0, image_size.1)));
+    }
+    if !non_negative(margin) || !non_negative(spacing) {
+        return Err(GameError::RuntimeError(format!("invalid tileset: margin {} and spacing {}", margin, spacing)));
+    }
+    Ok(())
+}
+
+pub(crate) fn resolve_path(base_dir: &Path, source: &str) -> String {
+    base_dir.join(source).to_string_lossy().replace('\\', "/")
+}
@@ -0,0 +1,229 @@
+use super::Tile;
+use tge::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ObjectShape {
+    #[default]
+    Rectangle,
+    Ellipse,
+    Point,
+    Polygon(Vec<Position>),
+    Polyline(Vec<Position>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapObject {
+    pub id: u32,
+    pub name: String,
+    pub class: String,
+    pub position: Position,
+    pub size: Size,
+    pub rotation: Angle,
+    pub visible: bool,
+    pub tile: Option<Tile>,
+    pub shape: ObjectShape,
+    pub properties: HashMap<String, String>,
+}
+
+impl MapObject {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            name: String::new(),
+            class: String::new(),
+            position: Position::zero(),
+            size: Size::new(0.0, 0.0),
+            rotation: Angle::zero(),
+            visible: true,
+            tile: None,
+            shape: ObjectShape::default(),
+            properties: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TileLayer {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) cols: usize,
+    pub(crate) rows: usize,
+    pub(crate) tiles: Vec<Tile>,
+    pub(crate) visible: bool,
+    pub(crate) opacity: f32,
+    pub(crate) offset: Vector,
+    pub(crate) properties: HashMap<String, String>,
+}
+
+impl TileLayer {
+    pub fn new(name: impl Into<String>, cols: usize, rows: usize) -> Self {
+        Self {
+            id: 0,
+            name: name.into(),
+            cols,
+            rows,
+            tiles: vec![Tile::default(); cols * rows],
+            visible: true,
+            opacity: 1.0,
+            offset: Vector::zero(),
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    pub fn tile(&self, col: usize, row: usize) -> Option<Tile> {
+        if col >= self.cols || row >= self.rows {
+            return None;
+        }
+        Some(self.tiles[row * self.cols + col]).filter(|tile| !tile.is_empty())
+    }
+
+    pub fn set_tile(&mut self, col: usize, row: usize, tile: Tile) {
+        if col < self.cols && row < self.rows {
+            self.tiles[row * self.cols + col] = tile;
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: impl Into<Vector>) {
+        self.offset = offset.into();
+    }
+
+    pub fn properties(&self) -> &HashMap<String, String> {
+        &self.properties
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectLayer {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) objects: Vec<MapObject>,
+    pub(crate) visible: bool,
+    pub(crate) opacity: f32,
+    pub(crate) offset: Vector,
+    pub(crate) properties: HashMap<String, String>,
+}
+
+impl ObjectLayer {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            name: name.into(),
+            objects: Vec::new(),
+            visible: true,
+            opacity: 1.0,
+            offset: Vector::zero(),
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn objects(&self) -> &[MapObject] {
+        &self.objects
+    }
+
+    pub fn objects_mut(&mut self) -> &mut Vec<MapObject> {
+        &mut self.objects
+    }
+
+    pub fn object_by_name(&self, name: impl AsRef<str>) -> Option<&MapObject> {
+        self.objects.iter().find(|object| object.name == name.as_ref())
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: impl Into<Vector>) {
+        self.offset = offset.into();
+    }
+
+    pub fn properties(&self) -> &HashMap<String, String> {
+        &self.properties
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Layer {
+    Tile(TileLayer),
+    Object(ObjectLayer),
+}
+
+impl Layer {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Tile(layer) => layer.name(),
+            Self::Object(layer) => layer.name(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        match self {
+            Self::Tile(layer) => layer.is_visible(),
+            Self::Object(layer) => layer.is_visible(),
+        }
+    }
+}
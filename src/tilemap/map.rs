@@ -0,0 +1,261 @@
+use super::{Tile, Tileset, Layer, TileLayer, ObjectLayer, tmx, tmj};
+use crate::asset::{AssetRegistry, TextureRefProvider};
+use crate::graphics::Frame;
+use crate::graphics::transform::{Transform2D, concat_transform};
+use tge::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct Tilemap {
+    pub(crate) cols: usize,
+    pub(crate) rows: usize,
+    pub(crate) tile_size: Size,
+    pub(crate) tilesets: Vec<Tileset>,
+    pub(crate) layers: Vec<Layer>,
+    pub(crate) background_color: Option<Color>,
+    pub(crate) properties: HashMap<String, String>,
+    pub(crate) elapsed: Duration,
+}
+
+impl Tilemap {
+    pub fn new(cols: usize, rows: usize, tile_size: impl Into<Size>) -> Self {
+        Self {
+            cols,
+            rows,
+            tile_size: tile_size.into(),
+            tilesets: Vec::new(),
+            layers: Vec::new(),
+            background_color: None,
+            properties: HashMap::new(),
+            elapsed: Duration::new(0, 0),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> GameResult<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|error| GameError::RuntimeError(format!("can not read tilemap: {}", error)))?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("tmx") => Self::from_tmx(&text, base_dir),
+            Some("tmj") | Some("json") => Self::from_tmj(&text, base_dir),
+            _ => Err(GameError::RuntimeError("unsupported tilemap format".into())),
+        }
+    }
+
+    pub fn from_tmx(text: &str, base_dir: impl AsRef<Path>) -> GameResult<Self> {
+        tmx::parse_map(text, base_dir.as_ref())
+    }
+
+    pub fn from_tmj(text: &str, base_dir: impl AsRef<Path>) -> GameResult<Self> {
+        tmj::parse_map(text, base_dir.as_ref())
+    }
+
+    pub fn register_textures(&self, engine: &mut Engine, registry: &mut AssetRegistry) -> GameResult {
+        for tileset in &self.tilesets {
+            registry.load_once::<Texture>(engine, tileset.res_name())?;
+        }
+        Ok(())
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn tile_size(&self) -> Size {
+        self.tile_size
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(self.cols as f32 * self.tile_size.width, self.rows as f32 * self.tile_size.height)
+    }
+
+    pub fn background_color(&self) -> Option<Color> {
+        self.background_color
+    }
+
+    pub fn set_background_color(&mut self, background_color: Option<Color>) {
+        self.background_color = background_color;
+    }
+
+    pub fn properties(&self) -> &HashMap<String, String> {
+        &self.properties
+    }
+
+    pub fn tilesets(&self) -> &[Tileset] {
+        &self.tilesets
+    }
+
+    pub fn push_tileset(&mut self, tileset: Tileset) {
+        let index = self.tilesets.iter().position(|other| other.first_gid() > tileset.first_gid()).unwrap_or(self.tilesets.len());
+        self.tilesets.insert(index, tileset);
+    }
+
+    pub fn find_tileset(&self, gid: u32) -> Option<&Tileset> {
+        self.tilesets.iter().rev().find(|tileset| tileset.first_gid() <= gid).filter(|tileset| tileset.contains_gid(gid))
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    pub fn layers_mut(&mut self) -> &mut Vec<Layer> {
+        &mut self.layers
+    }
+
+    pub fn push_layer(&mut self, layer: Layer) {
+        self.layers.push(layer);
+    }
+
+    pub fn layer(&self, name: impl AsRef<str>) -> Option<&Layer> {
+        self.layers.iter().find(|layer| layer.name() == name.as_ref())
+    }
+
+    pub fn tile_layer(&self, name: impl AsRef<str>) -> Option<&TileLayer> {
+        self.layers.iter().find_map(|layer| match layer {
+            Layer::Tile(layer) if layer.name() == name.as_ref() => Some(layer),
+            _ => None,
+        })
+    }
+
+    pub fn tile_layer_mut(&mut self, name: impl AsRef<str>) -> Option<&mut TileLayer> {
+        self.layers.iter_mut().find_map(|layer| match layer {
+            Layer::Tile(layer) if layer.name() == name.as_ref() => Some(layer),
+            _ => None,
+        })
+    }
+
+    pub fn object_layer(&self, name: impl AsRef<str>) -> Option<&ObjectLayer> {
+        self.layers.iter().find_map(|layer| match layer {
+            Layer::Object(layer) if layer.name() == name.as_ref() => Some(layer),
+            _ => None,
+        })
+    }
+
+    pub fn object_layer_mut(&mut self, name: impl AsRef<str>) -> Option<&mut ObjectLayer> {
+        self.layers.iter_mut().find_map(|layer| match layer {
+            Layer::Object(layer) if layer.name() == name.as_ref() => Some(layer),
+            _ => None,
+        })
+    }
+
+    pub fn tile_properties(&self, tile: Tile) -> Option<&HashMap<String, String>> {
+        let tileset = self.find_tileset(tile.gid())?;
+        tileset.properties(tile.gid() - tileset.first_gid())
+    }
+
+    pub fn tile_frame(&self, tile: Tile) -> Option<&Frame> {
+        let tileset = self.find_tileset(tile.gid())?;
+        tileset.frame(tileset.animated_tile_id(tile.gid() - tileset.first_gid(), self.elapsed))
+    }
+
+    pub fn convert_to_tile_position(&self, position: impl Into<Position>) -> Option<(usize, usize)> {
+        let position = position.into();
+        if position.x < 0.0 || position.y < 0.0 {
+            return None;
+        }
+        let col = (position.x / self.tile_size.width) as usize;
+        let row = (position.y / self.tile_size.height) as usize;
+        if col < self.cols && row < self.rows {
+            Some((col, row))
+        } else {
+            None
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        self.elapsed += delta_time;
+    }
+
+    pub fn draw(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, view: impl Into<Region>, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        let view = view.into();
+        let transform = transform.into();
+        for layer in &self.layers {
+            if let Layer::Tile(layer) = layer {
+                self.draw_tile_layer(graphics, provider, layer, view, transform)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn draw_layer(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, name: impl AsRef<str>, view: impl Into<Region>, transform: impl Into<Option<Transform2D>>) -> GameResult {
+        match self.tile_layer(name) {
+            Some(layer) => self.draw_tile_layer(graphics, provider, layer, view.into(), transform.into()),
+            None => Err(GameError::RuntimeError("tile layer not exists".into())),
+        }
+    }
+
+    fn draw_tile_layer(&self, graphics: &mut Graphics, provider: &impl TextureRefProvider, layer: &TileLayer, view: Region, transform: Option<Transform2D>) -> GameResult {
+        if !layer.is_visible() || layer.opacity() <= 0.0 {
+            return Ok(());
+        }
+        let tile_width = self.tile_size.width;
+        let tile_height = self.tile_size.height;
+        let (extra_cols, extra_rows) = self.tilesets.iter().fold((1, 1), |(cols, rows), tileset| {
+            let tile_size = tileset.tile_size();
+            let tile_offset = tileset.tile_offset();
+            (
+                cols.max(((tile_size.width + tile_offset.x.abs()) / tile_width).ceil() as usize),
+                rows.max(((tile_size.height + tile_offset.y.abs()) / tile_height).ceil() as usize),
+            )
+        });
+        let view_x = view.x - layer.offset().x;
+        let view_y = view.y - layer.offset().y;
+        let start_col = ((view_x / tile_width).floor().max(0.0) as usize).saturating_sub(extra_cols);
+        let start_row = ((view_y / tile_height).floor().max(0.0) as usize).saturating_sub(extra_rows);
+        let end_col = (((view_x + view.width) / tile_width).ceil().max(0.0) as usize).saturating_add(extra_cols).min(layer.cols());
+        let end_row = (((view_y + view.height) / tile_height).ceil().max(0.0) as usize).saturating_add(extra_rows).min(layer.rows());
+        let color = Color::new(1.0, 1.0, 1.0, layer.opacity());
+        for row in start_row..end_row {
+            for col in start_col..end_col {
+                let tile = match layer.tile(col, row) {
+                    Some(tile) => tile,
+                    None => continue,
+                };
+                let tileset = match self.find_tileset(tile.gid()) {
+                    Some(tileset) => tileset,
+                    None => continue,
+                };
+                let frame = match tileset.frame(tileset.animated_tile_id(tile.gid() - tileset.first_gid(), self.elapsed)) {
+                    Some(frame) => frame,
+                    None => continue,
+                };
+                let region = frame.region();
+                let x = layer.offset().x + col as f32 * tile_width + tileset.tile_offset().x;
+                let y = layer.offset().y + (row + 1) as f32 * tile_height - region.height + tileset.tile_offset().y;
+                let local = tile_transform(tile, Size::new(region.width, region.height), Position::new(x, y));
+                frame.draw_with(graphics, provider, color, (false, false), Some(concat_transform(local, transform)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn tile_transform(tile: Tile, size: Size, position: Position) -> Transform2D {
+    if !tile.flip_x() && !tile.flip_y() && !tile.flip_diagonal() {
+        return Transform2D::default().translate(position);
+    }
+    let half_width = size.width / 2.0;
+    let half_height = size.height / 2.0;
+    let mut transform = Transform2D::default().translate((-half_width, -half_height));
+    if tile.flip_diagonal() {
+        transform = transform.rotate(Angle::degrees(90.0)).scale((-1.0, 1.0));
+    }
+    transform
+        .scale((if tile.flip_x() { -1.0 } else { 1.0 }, if tile.flip_y() { -1.0 } else { 1.0 }))
+        .translate((position.x + half_width, position.y + half_height))
+}
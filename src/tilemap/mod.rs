@@ -0,0 +1,12 @@
+mod tile;
+mod data;
+mod tileset;
+mod layer;
+mod map;
+mod tmx;
+mod tmj;
+
+pub use tile::Tile;
+pub use tileset::{TileAnimationFrame, Tileset};
+pub use layer::{ObjectShape, MapObject, TileLayer, ObjectLayer, Layer};
+pub use map::Tilemap;
@@ -0,0 +1,78 @@
+const FLIPPED_HORIZONTALLY_FLAG: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY_FLAG: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY_FLAG: u32 = 0x2000_0000;
+const ROTATED_HEXAGONAL_120_FLAG: u32 = 0x1000_0000;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub struct Tile {
+    gid: u32,
+    flip_x: bool,
+    flip_y: bool,
+    flip_diagonal: bool,
+}
+
+impl Tile {
+    pub fn new(gid: u32) -> Self {
+        Self {
+            gid,
+            flip_x: false,
+            flip_y: false,
+            flip_diagonal: false,
+        }
+    }
+
+    pub fn from_raw(raw: u32) -> Self {
+        Self {
+            gid: raw & !(FLIPPED_HORIZONTALLY_FLAG | FLIPPED_VERTICALLY_FLAG | FLIPPED_DIAGONALLY_FLAG | ROTATED_HEXAGONAL_120_FLAG),
+            flip_x: raw & FLIPPED_HORIZONTALLY_FLAG != 0,
+            flip_y: raw & FLIPPED_VERTICALLY_FLAG != 0,
+            flip_diagonal: raw & FLIPPED_DIAGONALLY_FLAG != 0,
+        }
+    }
+
+    pub fn to_raw(&self) -> u32 {
+        let mut raw = self.gid;
+        if self.flip_x {
+            raw |= FLIPPED_HORIZONTALLY_FLAG;
+        }
+        if self.flip_y {
+            raw |= FLIPPED_VERTICALLY_FLAG;
+        }
+        if self.flip_diagonal {
+            raw |= FLIPPED_DIAGONALLY_FLAG;
+        }
+        raw
+    }
+
+    pub fn gid(&self) -> u32 {
+        self.gid
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.gid == 0
+    }
+
+    pub fn flip_x(&self) -> bool {
+        self.flip_x
+    }
+
+    pub fn set_flip_x(&mut self, flip_x: bool) {
+        self.flip_x = flip_x;
+    }
+
+    pub fn flip_y(&self) -> bool {
+        self.flip_y
+    }
+
+    pub fn set_flip_y(&mut self, flip_y: bool) {
+        self.flip_y = flip_y;
+    }
+
+    pub fn flip_diagonal(&self) -> bool {
+        self.flip_diagonal
+    }
+
+    pub fn set_flip_diagonal(&mut self, flip_diagonal: bool) {
+        self.flip_diagonal = flip_diagonal;
+    }
+}
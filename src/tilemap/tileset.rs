@@ -0,0 +1,145 @@
+use crate::graphics::{Frame, SheetGrid};
+use tge::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct TileAnimationFrame {
+    pub tile_id: u32,
+    pub duration: Duration,
+}
+
+#[derive(Clone)]
+pub struct Tileset {
+    first_gid: u32,
+    name: String,
+    res_name: String,
+    tile_size: Size,
+    image_size: Size,
+    margin: f32,
+    spacing: f32,
+    tile_offset: Vector,
+    frames: Vec<Frame>,
+    animations: HashMap<u32, Vec<TileAnimationFrame>>,
+    properties: HashMap<u32, HashMap<String, String>>,
+}
+
+impl Tileset {
+    pub fn new(first_gid: u32, name: impl Into<String>, res_name: impl Into<String>, tile_size: impl Into<Size>, image_size: impl Into<Size>, margin: f32, spacing: f32) -> Self {
+        let res_name = res_name.into();
+        let tile_size = tile_size.into();
+        let image_size = image_size.into();
+        let frames = SheetGrid::by_cell_size(tile_size)
+            .margin(margin)
+            .spacing(spacing)
+            .split(&res_name, (0.0, 0.0, image_size.width, image_size.height));
+        Self {
+            first_gid,
+            name: name.into(),
+            res_name,
+            tile_size,
+            image_size,
+            margin,
+            spacing,
+            tile_offset: Vector::zero(),
+            frames,
+            animations: HashMap::new(),
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn first_gid(&self) -> u32 {
+        self.first_gid
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn res_name(&self) -> &str {
+        &self.res_name
+    }
+
+    pub fn tile_size(&self) -> Size {
+        self.tile_size
+    }
+
+    pub fn image_size(&self) -> Size {
+        self.image_size
+    }
+
+    pub fn margin(&self) -> f32 {
+        self.margin
+    }
+
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    pub fn tile_offset(&self) -> Vector {
+        self.tile_offset
+    }
+
+    pub fn set_tile_offset(&mut self, tile_offset: impl Into<Vector>) {
+        self.tile_offset = tile_offset.into();
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn contains_gid(&self, gid: u32) -> bool {
+        gid >= self.first_gid && ((gid - self.first_gid) as usize) < self.frames.len()
+    }
+
+    pub fn frame(&self, tile_id: u32) -> Option<&Frame> {
+        self.frames.get(tile_id as usize)
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn animation(&self, tile_id: u32) -> Option<&[TileAnimationFrame]> {
+        self.animations.get(&tile_id).map(|animation| animation.as_slice())
+    }
+
+    pub fn set_animation(&mut self, tile_id: u32, animation: Vec<TileAnimationFrame>) {
+        if animation.is_empty() {
+            self.animations.remove(&tile_id);
+        } else {
+            self.animations.insert(tile_id, animation);
+        }
+    }
+
+    pub fn animated_tile_id(&self, tile_id: u32, time: Duration) -> u32 {
+        let animation = match self.animations.get(&tile_id) {
+            Some(animation) => animation,
+            None => return tile_id,
+        };
+        let period = animation.iter().map(|frame| frame.duration.as_nanos()).sum::<u128>();
+        if period == 0 {
+            return tile_id;
+        }
+        let mut time = time.as_nanos() % period;
+        for frame in animation {
+            if time < frame.duration.as_nanos() {
+                return frame.tile_id;
+            }
+            time -= frame.duration.as_nanos();
+        }
+        tile_id
+    }
+
+    pub fn properties(&self, tile_id: u32) -> Option<&HashMap<String, String>> {
+        self.properties.get(&tile_id)
+    }
+
+    pub fn set_properties(&mut self, tile_id: u32, properties: HashMap<String, String>) {
+        if properties.is_empty() {
+            self.properties.remove(&tile_id);
+        } else {
+            self.properties.insert(tile_id, properties);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use super::{Tile, Tileset, TileAnimationFrame, Tilemap, Layer, TileLayer, ObjectLayer, MapObject, ObjectShape};
+use super::data::{decode_tiles, check_len, check_map_tile_size, layer_len, check_tileset_size, parse_color, resolve_path};
+use tge::prelude::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+pub(crate) fn parse_map(text: &str, base_dir: &Path) -> GameResult<Tilemap> {
+    let value = parse_value(text)?;
+    if let Some(orientation) = string(&value, "orientation")? {
+        if orientation != "orthogonal" {
+            return Err(GameError::RuntimeError(format!("unsupported map orientation: {}", orientation)));
+        }
+    }
+    if value.get("infinite").and_then(Value::as_bool) == Some(true) {
+        return Err(GameError::RuntimeError("infinite maps are not supported".into()));
+    }
+    let tile_size = (required_number(&value, "tilewidth")? as f32, required_number(&value, "tileheight")? as f32);
+    check_map_tile_size(tile_size)?;
+    let mut tilemap = Tilemap::new(required_number(&value, "width")? as usize, required_number(&value, "height")? as usize, tile_size);
+    if let Some(background_color) = string(&value, "backgroundcolor")? {
+        tilemap.background_color = Some(parse_color(background_color)?);
+    }
+    tilemap.properties = properties(&value);
+    for tileset in array(&value, "tilesets")? {
+        tilemap.push_tileset(parse_tileset(tileset, base_dir)?);
+    }
+    for layer in array(&value, "layers")? {
+        parse_layer(layer, Vector::zero(), 1.0, true, &mut tilemap.layers)?;
+    }
+    Ok(tilemap)
+}
+
+fn parse_value(text: &str) -> GameResult<Value> {
+    serde_json::from_str(text).map_err(|error| GameError::RuntimeError(format!("invalid tmj document: {}", error)))
+}
+
+fn number(value: &Value, name: &str) -> GameResult<Option<f64>> {
+    match value.get(name) {
+        Some(number) => number.as_f64()
+            .map(Some)
+            .ok_or_else(|| GameError::RuntimeError(format!("invalid field `{}`", name))),
+        None => Ok(None),
+    }
+}
+
+fn required_number(value: &Value, name: &str) -> GameResult<f64> {
+    number(value, name)?.ok_or_else(|| GameError::RuntimeError(format!("missing field `{}`", name)))
+}
+
+fn string<'a>(value: &'a Value, name: &str) -> GameResult<Option<&'a str>> {
+    match value.get(name) {
+        Some(string) => string.as_str()
+            .map(Some)
+            .ok_or_else(|| GameError::RuntimeError(format!("invalid field `{}`", name))),
+        None => Ok(None),
+    }
+}
+
+fn array<'a>(value: &'a Value, name: &str) -> GameResult<&'a [Value]> {
+    match value.get(name) {
+        Some(array) => array.as_array()
+            .map(|array| array.as_slice())
+            .ok_or_else(|| GameError::RuntimeError(format!("invalid field `{}`", name))),
+        None => Ok(&[]),
+    }
+}
+
+fn properties(value: &Value) -> HashMap<String, String> {
+    value.get("properties")
+        .and_then(Value::as_array)
+        .map(|properties| properties.iter()
+            .filter_map(|property| {
+                let name = property.get("name")?.as_str()?;
+                let value = match property.get("value")? {
+                    Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                };
+                Some((name.to_owned(), value))
+            })
+            .collect())
+        .unwrap_or_default()
+}
+
+fn parse_tileset(value: &Value, base_dir: &Path) -> GameResult<Tileset> {
+    let first_gid = required_number(value, "firstgid")? as u32;
+    match string(value, "source")? {
+        Some(source) => {
+            let path = base_dir.join(source);
+            let text = fs::read_to_string(&path).map_err(|error| GameError::RuntimeError(format!("can not read tileset: {}", error)))?;
+            parse_tileset_content(&parse_value(&text)?, first_gid, path.parent().unwrap_or(base_dir))
+        }
+        None => parse_tileset_content(value, first_gid, base_dir),
+    }
+}
+
+fn parse_tileset_content(value: &Value, first_gid: u32, base_dir: &Path) -> GameResult<Tileset> {
+    let image = string(value, "image")?
+        .ok_or_else(|| GameError::RuntimeError("image collection tilesets are not supported".into()))?;
+    let tile_size = (required_number(value, "tilewidth")? as f32, required_number(value, "tileheight")? as f32);
+    let image_size = (required_number(value, "imagewidth")? as f32, required_number(value, "imageheight")? as f32);
+    let margin = number(value, "margin")?.unwrap_or(0.0) as f32;
+    let spacing = number(value, "spacing")?.unwrap_or(0.0) as f32;
+    check_tileset_size(tile_size, image_size, margin, spacing)?;
+    let mut tileset = Tileset::new(
+        first_gid,
+        string(value, "name")?.unwrap_or(""),
+        resolve_path(base_dir, image),
+        tile_size,
+        image_size,
+        margin,
+        spacing,
+    );
+    if let Some(tile_offset) = value.get("tileoffset") {
+        tileset.set_tile_offset((number(tile_offset, "x")?.unwrap_or(0.0) as f32, number(tile_offset, "y")?.unwrap_or(0.0) as f32));
+    }
+    for tile in array(value, "tiles")? {
+        let tile_id = required_number(tile, "id")? as u32;
+        let frames = array(tile, "animation")?
+            .iter()
+            .map(|frame| Ok(TileAnimationFrame {
+                tile_id: required_number(frame, "tileid")? as u32,
+                duration: Duration::from_millis(required_number(frame, "duration")? as u64),
+            }))
+            .collect::<GameResult<Vec<_>>>()?;
+        tileset.set_animation(tile_id, frames);
+        tileset.set_properties(tile_id, properties(tile));
+    }
+    Ok(tileset)
+}
+
+fn parse_layer(value: &Value, parent_offset: Vector, parent_opacity: f32, parent_visible: bool, layers: &mut Vec<Layer>) -> GameResult {
+    let offset = Vector::new(
+        parent_offset.x + number(value, "offsetx")?.unwrap_or(0.0) as f32,
+        parent_offset.y + number(value, "offsety")?.unwrap_or(0.0) as f32,
+    );
+    let opacity = parent_opacity * number(value, "opacity")?.unwrap_or(1.0) as f32;
+    let visible = parent_visible && value.get("visible").and_then(Value::as_bool) != Some(false);
+    let name = string(value, "name")?.unwrap_or("");
+    let id = number(value, "id")?.unwrap_or(0.0) as u32;
+    match string(value, "type")? {
+        Some("tilelayer") => {
+            let cols = required_number(value, "width")? as usize;
+            let rows = required_number(value, "height")? as usize;
+            let len = layer_len(cols, rows)?;
+            let tiles = match value.get("data") {
+                Some(Value::String(data)) => decode_tiles(data, string(value, "encoding")?, string(value, "compression")?, len)?,
+                Some(Value::Array(data)) => {
+                    let tiles = data.iter()
+                        .map(|raw| raw.as_u64()
+                            .map(|raw| Tile::from_raw(raw as u32))
+                            .ok_or_else(|| GameError::RuntimeError("invalid tile data".into())))
+                        .collect::<GameResult<Vec<_>>>()?;
+                    check_len(tiles, len)?
+                }
+                _ => return Err(GameError::RuntimeError("missing layer data".into())),
+            };
+            let mut layer = TileLayer::new(name, cols, rows);
+            layer.id = id;
+            layer.offset = offset;
+            layer.opacity = opacity;
+            layer.visible = visible;
+            layer.properties = properties(value);
+            layer.tiles = tiles;
+            layers.push(Layer::Tile(layer));
+        }
+        Some("objectgroup") => {
+            let mut layer = ObjectLayer::new(name);
+            layer.id = id;
+            layer.offset = offset;
+            layer.opacity = opacity;
+            layer.visible = visible;
+            layer.properties = properties(value);
+            layer.objects = array(value, "objects")?
+                .iter()
+                .map(parse_object)
+                .collect::<GameResult<Vec<_>>>()?;
+            layers.push(Layer::Object(layer));
+        }
+        Some("group") => {
+            for layer in array(value, "layers")? {
+                parse_layer(layer, offset, opacity, visible, layers)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+fn parse_object(value: &Value) -> GameResult<MapObject> {
+    let mut object = MapObject::new(number(value, "id")?.unwrap_or(0.0) as u32);
+    object.name = string(value, "name")?.unwrap_or("").to_owned();
+    object.class = match string(value, "class")? {
+        Some(class) => class,
+        None => string(value, "type")?.unwrap_or(""),
+    }.to_owned();
+    object.position = Position::new(number(value, "x")?.unwrap_or(0.0) as f32, number(value, "y")?.unwrap_or(0.0) as f32);
+    object.size = Size::new(number(value, "width")?.unwrap_or(0.0) as f32, number(value, "height")?.unwrap_or(0.0) as f32);
+    object.rotation = Angle::degrees(number(value, "rotation")?.unwrap_or(0.0) as f32);
+    object.visible = value.get("visible").and_then(Value::as_bool) != Some(false);
+    object.tile = number(value, "gid")?.map(|gid| Tile::from_raw(gid as u32));
+    object.properties = properties(value);
+    if value.get("ellipse").and_then(Value::as_bool) == Some(true) {
+        object.shape = ObjectShape::Ellipse;
+    } else if value.get("point").and_then(Value::as_bool) == Some(true) {
+        object.shape = ObjectShape::Point;
+    } else if value.get("polygon").is_some() {
+        object.shape = ObjectShape::Polygon(parse_points(array(value, "polygon")?)?);
+    } else if value.get("polyline").is_some() {
+        object.shape = ObjectShape::Polyline(parse_points(array(value, "polyline")?)?);
+    }
+    Ok(object)
+}
+
+fn parse_points(points: &[Value]) -> GameResult<Vec<Position>> {
+    points.iter()
+        .map(|point| Ok(Position::new(required_number(point, "x")? as f32, required_number(point, "y")? as f32)))
+        .collect()
+}
@@ -0,0 +1,217 @@
+use super::{Tile, Tileset, TileAnimationFrame, Tilemap, Layer, TileLayer, ObjectLayer, MapObject, ObjectShape};
+use super::data::{decode_tiles, check_len, check_map_tile_size, layer_len, check_tileset_size, parse_color, resolve_path};
+use tge::prelude::*;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub(crate) fn parse_map(text: &str, base_dir: &Path) -> GameResult<Tilemap> {
+    let document = parse_document(text)?;
+    let node = document.root_element();
+    if !node.has_tag_name("map") {
+        return Err(GameError::RuntimeError("not a tmx map".into()));
+    }
+    if let Some(orientation) = node.attribute("orientation") {
+        if orientation != "orthogonal" {
+            return Err(GameError::RuntimeError(format!("unsupported map orientation: {}", orientation)));
+        }
+    }
+    if node.attribute("infinite") == Some("1") {
+        return Err(GameError::RuntimeError("infinite maps are not supported".into()));
+    }
+    let tile_size = (required_attribute::<f32>(node, "tilewidth")?, required_attribute::<f32>(node, "tileheight")?);
+    check_map_tile_size(tile_size)?;
+    let mut tilemap = Tilemap::new(required_attribute(node, "width")?, required_attribute(node, "height")?, tile_size);
+    if let Some(background_color) = node.attribute("backgroundcolor") {
+        tilemap.background_color = Some(parse_color(background_color)?);
+    }
+    for child in node.children().filter(|child| child.is_element()) {
+        match child.tag_name().name() {
+            "tileset" => tilemap.push_tileset(parse_tileset(child, base_dir)?),
+            "properties" => tilemap.properties = parse_properties(child),
+            _ => parse_layer(child, Vector::zero(), 1.0, true, &mut tilemap.layers)?,
+        }
+    }
+    Ok(tilemap)
+}
+
+fn parse_document(text: &str) -> GameResult<Document<'_>> {
+    Document::parse(text).map_err(|error| GameError::RuntimeError(format!("invalid tmx document: {}", error)))
+}
+
+fn attribute<T: FromStr>(node: Node, name: &str) -> GameResult<Option<T>> {
+    match node.attribute(name) {
+        Some(value) => value.parse()
+            .map(Some)
+            .map_err(|_| GameError::RuntimeError(format!("invalid attribute `{}`: {}", name, value))),
+        None => Ok(None),
+    }
+}
+
+fn required_attribute<T: FromStr>(node: Node, name: &str) -> GameResult<T> {
+    attribute(node, name)?.ok_or_else(|| GameError::RuntimeError(format!("missing attribute `{}`", name)))
+}
+
+fn parse_properties(node: Node) -> HashMap<String, String> {
+    node.children()
+        .filter(|child| child.has_tag_name("property"))
+        .filter_map(|child| {
+            let name = child.attribute("name")?;
+            let value = child.attribute("value").or_else(|| child.text()).unwrap_or("");
+            Some((name.to_owned(), value.to_owned()))
+        })
+        .collect()
+}
+
+fn child_properties(node: Node) -> HashMap<String, String> {
+    node.children()
+        .find(|child| child.has_tag_name("properties"))
+        .map(parse_properties)
+        .unwrap_or_default()
+}
+
+fn parse_tileset(node: Node, base_dir: &Path) -> GameResult<Tileset> {
+    let first_gid = required_attribute(node, "firstgid")?;
+    match node.attribute("source") {
+        Some(source) => {
+            let path = base_dir.join(source);
+            let text = fs::read_to_string(&path).map_err(|error| GameError::RuntimeError(format!("can not read tileset: {}", error)))?;
+            let document = parse_document(&text)?;
+            parse_tileset_content(document.root_element(), first_gid, path.parent().unwrap_or(base_dir))
+        }
+        None => parse_tileset_content(node, first_gid, base_dir),
+    }
+}
+
+fn parse_tileset_content(node: Node, first_gid: u32, base_dir: &Path) -> GameResult<Tileset> {
+    let image = node.children()
+        .find(|child| child.has_tag_name("image"))
+        .ok_or_else(|| GameError::RuntimeError("image collection tilesets are not supported".into()))?;
+    let tile_size = (required_attribute::<f32>(node, "tilewidth")?, required_attribute::<f32>(node, "tileheight")?);
+    let image_size = (required_attribute::<f32>(image, "width")?, required_attribute::<f32>(image, "height")?);
+    let margin = attribute(node, "margin")?.unwrap_or(0.0);
+    let spacing = attribute(node, "spacing")?.unwrap_or(0.0);
+    check_tileset_size(tile_size, image_size, margin, spacing)?;
+    let mut tileset = Tileset::new(
+        first_gid,
+        node.attribute("name").unwrap_or(""),
+        resolve_path(base_dir, required_attribute::<String>(image, "source")?.as_str()),
+        tile_size,
+        image_size,
+        margin,
+        spacing,
+    );
+    if let Some(tile_offset) = node.children().find(|child| child.has_tag_name("tileoffset")) {
+        tileset.set_tile_offset((attribute(tile_offset, "x")?.unwrap_or(0.0), attribute(tile_offset, "y")?.unwrap_or(0.0)));
+    }
+    for tile in node.children().filter(|child| child.has_tag_name("tile")) {
+        let tile_id = required_attribute(tile, "id")?;
+        if let Some(animation) = tile.children().find(|child| child.has_tag_name("animation")) {
+            let frames = animation.children()
+                .filter(|child| child.has_tag_name("frame"))
+                .map(|frame| Ok(TileAnimationFrame {
+                    tile_id: required_attribute(frame, "tileid")?,
+                    duration: Duration::from_millis(required_attribute(frame, "duration")?),
+                }))
+                .collect::<GameResult<Vec<_>>>()?;
+            tileset.set_animation(tile_id, frames);
+        }
+        tileset.set_properties(tile_id, child_properties(tile));
+    }
+    Ok(tileset)
+}
+
+fn parse_layer(node: Node, parent_offset: Vector, parent_opacity: f32, parent_visible: bool, layers: &mut Vec<Layer>) -> GameResult {
+    let offset = Vector::new(
+        parent_offset.x + attribute(node, "offsetx")?.unwrap_or(0.0),
+        parent_offset.y + attribute(node, "offsety")?.unwrap_or(0.0),
+    );
+    let opacity = parent_opacity * attribute(node, "opacity")?.unwrap_or(1.0);
+    let visible = parent_visible && node.attribute("visible") != Some("0");
+    match node.tag_name().name() {
+        "layer" => {
+            let cols = required_attribute(node, "width")?;
+            let rows = required_attribute(node, "height")?;
+            let len = layer_len(cols, rows)?;
+            let data = node.children()
+                .find(|child| child.has_tag_name("data"))
+                .ok_or_else(|| GameError::RuntimeError("missing layer data".into()))?;
+            let tiles = match data.attribute("encoding") {
+                Some(encoding) => decode_tiles(data.text().unwrap_or(""), Some(encoding), data.attribute("compression"), len)?,
+                None => {
+                    let tiles = data.children()
+                        .filter(|child| child.has_tag_name("tile"))
+                        .map(|tile| Ok(Tile::from_raw(attribute(tile, "gid")?.unwrap_or(0))))
+                        .collect::<GameResult<Vec<_>>>()?;
+                    check_len(tiles, len)?
+                }
+            };
+            let mut layer = TileLayer::new(node.attribute("name").unwrap_or(""), cols, rows);
+            layer.id = attribute(node, "id")?.unwrap_or(0);
+            layer.offset = offset;
+            layer.opacity = opacity;
+            layer.visible = visible;
+            layer.properties = child_properties(node);
+            layer.tiles = tiles;
+            layers.push(Layer::Tile(layer));
+        }
+        "objectgroup" => {
+            let mut layer = ObjectLayer::new(node.attribute("name").unwrap_or(""));
+            layer.id = attribute(node, "id")?.unwrap_or(0);
+            layer.offset = offset;
+            layer.opacity = opacity;
+            layer.visible = visible;
+            layer.properties = child_properties(node);
+            layer.objects = node.children()
+                .filter(|child| child.has_tag_name("object"))
+                .map(parse_object)
+                .collect::<GameResult<Vec<_>>>()?;
+            layers.push(Layer::Object(layer));
+        }
+        "group" => {
+            for child in node.children().filter(|child| child.is_element()) {
+                parse_layer(child, offset, opacity, visible, layers)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+fn parse_object(node: Node) -> GameResult<MapObject> {
+    let mut object = MapObject::new(attribute(node, "id")?.unwrap_or(0));
+    object.name = node.attribute("name").unwrap_or("").to_owned();
+    object.class = node.attribute("class").or_else(|| node.attribute("type")).unwrap_or("").to_owned();
+    object.position = Position::new(attribute(node, "x")?.unwrap_or(0.0), attribute(node, "y")?.unwrap_or(0.0));
+    object.size = Size::new(attribute(node, "width")?.unwrap_or(0.0), attribute(node, "height")?.unwrap_or(0.0));
+    object.rotation = Angle::degrees(attribute(node, "rotation")?.unwrap_or(0.0));
+    object.visible = node.attribute("visible") != Some("0");
+    object.tile = attribute(node, "gid")?.map(Tile::from_raw);
+    object.properties = child_properties(node);
+    for child in node.children().filter(|child| child.is_element()) {
+        match child.tag_name().name() {
+            "ellipse" => object.shape = ObjectShape::Ellipse,
+            "point" => object.shape = ObjectShape::Point,
+            "polygon" => object.shape = ObjectShape::Polygon(parse_points(child)?),
+            "polyline" => object.shape = ObjectShape::Polyline(parse_points(child)?),
+            _ => (),
+        }
+    }
+    Ok(object)
+}
+
+fn parse_points(node: Node) -> GameResult<Vec<Position>> {
+    required_attribute::<String>(node, "points")?
+        .split_whitespace()
+        .map(|point| {
+            let mut values = point.split(',').map(|value| value.parse::<f32>());
+            match (values.next(), values.next()) {
+                (Some(Ok(x)), Some(Ok(y))) => Ok(Position::new(x, y)),
+                _ => Err(GameError::RuntimeError(format!("invalid point: {}", point))),
+            }
+        })
+        .collect()
+}
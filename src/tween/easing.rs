@@ -0,0 +1,173 @@
+use std::f32::consts::PI;
+use std::mem;
+
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    Custom(fn(f32) -> f32),
+}
+
+const NAMED_EASINGS: [(&str, Easing); 28] = [
+    ("linear", Easing::Linear),
+    ("quad_in", Easing::QuadIn),
+    ("quad_out", Easing::QuadOut),
+    ("quad_in_out", Easing::QuadInOut),
+    ("cubic_in", Easing::CubicIn),
+    ("cubic_out", Easing::CubicOut),
+    ("cubic_in_out", Easing::CubicInOut),
+    ("quart_in", Easing::QuartIn),
+    ("quart_out", Easing::QuartOut),
+    ("quart_in_out", Easing::QuartInOut),
+    ("sine_in", Easing::SineIn),
+    ("sine_out", Easing::SineOut),
+    ("sine_in_out", Easing::SineInOut),
+    ("expo_in", Easing::ExpoIn),
+    ("expo_out", Easing::ExpoOut),
+    ("expo_in_out", Easing::ExpoInOut),
+    ("circ_in", Easing::CircIn),
+    ("circ_out", Easing::CircOut),
+    ("circ_in_out", Easing::CircInOut),
+    ("back_in", Easing::BackIn),
+    ("back_out", Easing::BackOut),
+    ("back_in_out", Easing::BackInOut),
+    ("elastic_in", Easing::ElasticIn),
+    ("elastic_out", Easing::ElasticOut),
+    ("elastic_in_out", Easing::ElasticInOut),
+    ("bounce_in", Easing::BounceIn),
+    ("bounce_out", Easing::BounceOut),
+    ("bounce_in_out", Easing::BounceInOut),
+];
+
+impl Easing {
+    pub fn by_name(name: &str) -> Option<Self> {
+        NAMED_EASINGS.iter()
+            .find(|(easing_name, _)| *easing_name == name)
+            .map(|(_, easing)| *easing)
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED_EASINGS.iter()
+            .find(|(_, easing)| mem::discriminant(easing) == mem::discriminant(self))
+            .map(|(easing_name, _)| *easing_name)
+    }
+
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => out(t, |t| t * t),
+            Self::QuadInOut => in_out(t, |t| t * t),
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => out(t, |t| t * t * t),
+            Self::CubicInOut => in_out(t, |t| t * t * t),
+            Self::QuartIn => t.powi(4),
+            Self::QuartOut => out(t, |t| t.powi(4)),
+            Self::QuartInOut => in_out(t, |t| t.powi(4)),
+            Self::SineIn => sine_in(t),
+            Self::SineOut => out(t, sine_in),
+            Self::SineInOut => in_out(t, sine_in),
+            Self::ExpoIn => expo_in(t),
+            Self::ExpoOut => out(t, expo_in),
+            Self::ExpoInOut => in_out(t, expo_in),
+            Self::CircIn => circ_in(t),
+            Self::CircOut => out(t, circ_in),
+            Self::CircInOut => in_out(t, circ_in),
+            Self::BackIn => back_in(t),
+            Self::BackOut => out(t, back_in),
+            Self::BackInOut => in_out(t, back_in),
+            Self::ElasticIn => elastic_in(t),
+            Self::ElasticOut => out(t, elastic_in),
+            Self::ElasticInOut => in_out(t, elastic_in),
+            Self::BounceIn => out(t, bounce_out),
+            Self::BounceOut => bounce_out(t),
+            Self::BounceInOut => in_out(t, |t| out(t, bounce_out)),
+            Self::Custom(f) => f(t),
+        }
+    }
+}
+
+fn out(t: f32, f: impl Fn(f32) -> f32) -> f32 {
+    1.0 - f(1.0 - t)
+}
+
+fn in_out(t: f32, f: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        f(t * 2.0) / 2.0
+    } else {
+        1.0 - f((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+fn sine_in(t: f32) -> f32 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2.0f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn circ_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+fn back_in(t: f32) -> f32 {
+    let overshoot = 1.701_58;
+    t * t * ((overshoot + 1.0) * t - overshoot)
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    -(2.0f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * (2.0 * PI / 3.0)).sin()
+}
+
+fn bounce_out(t: f32) -> f32 {
+    let n = 7.5625;
+    let d = 2.75;
+    if t < 1.0 / d {
+        n * t * t
+    } else if t < 2.0 / d {
+        let t = t - 1.5 / d;
+        n * t * t + 0.75
+    } else if t < 2.5 / d {
+        let t = t - 2.25 / d;
+        n * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d;
+        n * t * t + 0.984_375
+    }
+}
@@ -0,0 +1,176 @@
+use super::Tweener;
+use std::any::Any;
+use std::time::Duration;
+
+pub struct TweenSequence {
+    tweeners: Vec<Box<dyn Tweener>>,
+    current_index: usize,
+}
+
+impl TweenSequence {
+    pub fn new() -> Self {
+        Self {
+            tweeners: Vec::new(),
+            current_index: 0,
+        }
+    }
+
+    pub fn push(&mut self, tweener: impl Tweener) -> usize {
+        self.tweeners.push(Box::new(tweener));
+        self.tweeners.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.tweeners.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tweeners.is_empty()
+    }
+
+    pub fn get<T: Tweener>(&self, index: usize) -> Option<&T> {
+        self.tweeners.get(index).and_then(|tweener| tweener.as_any().downcast_ref())
+    }
+
+    pub fn get_mut<T: Tweener>(&mut self, index: usize) -> Option<&mut T> {
+        self.tweeners.get_mut(index).and_then(|tweener| tweener.as_any_mut().downcast_mut())
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current_index.min(self.tweeners.len().saturating_sub(1))
+    }
+
+    pub fn update(&mut self, delta_time: Duration) -> Duration {
+        let mut remaining = delta_time;
+        while let Some(tweener) = self.tweeners.get_mut(self.current_index) {
+            remaining = tweener.update(remaining);
+            if !tweener.is_finished() {
+                return Duration::new(0, 0);
+            }
+            self.current_index += 1;
+        }
+        remaining
+    }
+
+    pub fn reset(&mut self) {
+        for tweener in &mut self.tweeners {
+            tweener.reset();
+        }
+        self.current_index = 0;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_index >= self.tweeners.len()
+    }
+}
+
+impl Default for TweenSequence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tweener for TweenSequence {
+    fn update(&mut self, delta_time: Duration) -> Duration {
+        TweenSequence::update(self, delta_time)
+    }
+
+    fn reset(&mut self) {
+        TweenSequence::reset(self)
+    }
+
+    fn is_finished(&self) -> bool {
+        TweenSequence::is_finished(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+pub struct TweenParallel {
+    tweeners: Vec<Box<dyn Tweener>>,
+}
+
+impl TweenParallel {
+    pub fn new() -> Self {
+        Self {
+            tweeners: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, tweener: impl Tweener) -> usize {
+        self.tweeners.push(Box::new(tweener));
+        self.tweeners.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.tweeners.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tweeners.is_empty()
+    }
+
+    pub fn get<T: Tweener>(&self, index: usize) -> Option<&T> {
+        self.tweeners.get(index).and_then(|tweener| tweener.as_any().downcast_ref())
+    }
+
+    pub fn get_mut<T: Tweener>(&mut self, index: usize) -> Option<&mut T> {
+        self.tweeners.get_mut(index).and_then(|tweener| tweener.as_any_mut().downcast_mut())
+    }
+
+    pub fn update(&mut self, delta_time: Duration) -> Duration {
+        let mut remaining = delta_time;
+        for tweener in &mut self.tweeners {
+            remaining = remaining.min(tweener.update(delta_time));
+        }
+        if self.is_finished() {
+            remaining
+        } else {
+            Duration::new(0, 0)
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for tweener in &mut self.tweeners {
+            tweener.reset();
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.tweeners.iter().all(|tweener| tweener.is_finished())
+    }
+}
+
+impl Default for TweenParallel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tweener for TweenParallel {
+    fn update(&mut self, delta_time: Duration) -> Duration {
+        TweenParallel::update(self, delta_time)
+    }
+
+    fn reset(&mut self) {
+        TweenParallel::reset(self)
+    }
+
+    fn is_finished(&self) -> bool {
+        TweenParallel::is_finished(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,34 @@
+use tge::prelude::*;
+
+pub trait Lerp: Copy {
+    fn lerp(self, to: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for Vector {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        Vector::new(self.x.lerp(to.x, t), self.y.lerp(to.y, t))
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        Color::new(
+            self.red.lerp(to.red, t),
+            self.green.lerp(to.green, t),
+            self.blue.lerp(to.blue, t),
+            self.alpha.lerp(to.alpha, t),
+        )
+    }
+}
+
+impl Lerp for Angle {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        Angle::radians(self.radians_value().lerp(to.radians_value(), t))
+    }
+}
@@ -0,0 +1,11 @@
+mod easing;
+mod lerp;
+mod tweener;
+mod value;
+mod group;
+
+pub use easing::Easing;
+pub use lerp::Lerp;
+pub use tweener::Tweener;
+pub use value::Tween;
+pub use group::{TweenSequence, TweenParallel};
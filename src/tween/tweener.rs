@@ -0,0 +1,14 @@
+use std::any::Any;
+use std::time::Duration;
+
+pub trait Tweener: Any + Send + Sync {
+    fn update(&mut self, delta_time: Duration) -> Duration;
+
+    fn reset(&mut self);
+
+    fn is_finished(&self) -> bool;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
@@ -0,0 +1,204 @@
+use super::{Easing, Lerp, Tweener};
+use crate::graphics::RepeatCount;
+use std::any::Any;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Tween<T: Lerp> {
+    from: T,
+    to: T,
+    duration: Duration,
+    easing: Easing,
+    delay: Duration,
+    repeat_count: RepeatCount,
+    yoyo: bool,
+    elapsed: Duration,
+    just_finished: bool,
+}
+
+impl<T: Lerp> Tween<T> {
+    pub fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing: Easing::default(),
+            delay: Duration::new(0, 0),
+            repeat_count: RepeatCount::Count(1),
+            yoyo: false,
+            elapsed: Duration::new(0, 0),
+            just_finished: false,
+        }
+    }
+
+    pub fn from(&self) -> T {
+        self.from
+    }
+
+    pub fn set_from(&mut self, from: T) {
+        self.from = from;
+    }
+
+    pub fn to(&self) -> T {
+        self.to
+    }
+
+    pub fn set_to(&mut self, to: T) {
+        self.to = to;
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    pub fn repeat_count(&self) -> RepeatCount {
+        self.repeat_count
+    }
+
+    pub fn set_repeat_count(&mut self, repeat_count: RepeatCount) {
+        self.repeat_count = repeat_count;
+    }
+
+    pub fn is_yoyo(&self) -> bool {
+        self.yoyo
+    }
+
+    pub fn set_yoyo(&mut self, yoyo: bool) {
+        self.yoyo = yoyo;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = match self.total_duration() {
+            Some(total_duration) => elapsed.min(total_duration),
+            None => elapsed,
+        };
+        self.just_finished = false;
+    }
+
+    pub fn total_duration(&self) -> Option<Duration> {
+        match self.repeat_count {
+            RepeatCount::Infinite => None,
+            RepeatCount::Count(count) => Some(self.delay + self.duration * count as u32),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::new(0, 0);
+        self.just_finished = false;
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.elapsed > self.delay
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match self.total_duration() {
+            Some(total_duration) => self.elapsed >= total_duration,
+            None => false,
+        }
+    }
+
+    pub fn is_just_finished(&self) -> bool {
+        self.just_finished
+    }
+
+    pub fn play_count(&self) -> usize {
+        self.locate().0
+    }
+
+    pub fn progress(&self) -> f32 {
+        self.locate().1
+    }
+
+    pub fn value(&self) -> T {
+        let (play_count, progress) = self.locate();
+        let progress = if self.yoyo && play_count % 2 == 1 {
+            1.0 - progress
+        } else {
+            progress
+        };
+        self.from.lerp(self.to, self.easing.apply(progress))
+    }
+
+    fn locate(&self) -> (usize, f32) {
+        if self.is_finished() {
+            return match self.repeat_count {
+                RepeatCount::Count(count) if count > 0 => (count - 1, 1.0),
+                _ => (0, 0.0),
+            };
+        }
+        let active = self.elapsed.saturating_sub(self.delay);
+        if self.duration.as_nanos() == 0 {
+            return (0, 0.0);
+        }
+        let play_count = (active.as_nanos() / self.duration.as_nanos()) as usize;
+        let since = active - self.duration * play_count as u32;
+        (play_count, since.as_secs_f32() / self.duration.as_secs_f32())
+    }
+
+    pub fn update(&mut self, delta_time: Duration) -> Duration {
+        if self.is_finished() {
+            self.just_finished = false;
+            return delta_time;
+        }
+        self.elapsed += delta_time;
+        match self.total_duration() {
+            Some(total_duration) if self.elapsed >= total_duration => {
+                let remaining = self.elapsed - total_duration;
+                self.elapsed = total_duration;
+                self.just_finished = true;
+                remaining
+            }
+            _ => {
+                self.just_finished = false;
+                Duration::new(0, 0)
+            }
+        }
+    }
+}
+
+impl<T: Lerp + Send + Sync + 'static> Tweener for Tween<T> {
+    fn update(&mut self, delta_time: Duration) -> Duration {
+        Tween::update(self, delta_time)
+    }
+
+    fn reset(&mut self) {
+        Tween::reset(self)
+    }
+
+    fn is_finished(&self) -> bool {
+        Tween::is_finished(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}